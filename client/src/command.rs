@@ -0,0 +1,4 @@
+pub use crate::core::{
+	AddCommandEvent, AddCommandTask, AddCvar, CommandEvent, Cvar, Permission, Progress,
+	RemoveCommand, ScopeCommand, SetPermission, Value,
+};
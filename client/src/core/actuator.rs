@@ -1,13 +1,27 @@
-use std::collections::{vec_deque::Iter, HashMap};
+use std::{
+	collections::{HashMap, HashSet, VecDeque},
+	env, fs, io,
+	path::Path,
+	time::Duration,
+};
 
 use bevy::{ecs::system::SystemId, prelude::Resource};
-use clap::ArgMatches;
+use clap::{error::ErrorKind, ArgMatches};
 use shlex::Shlex;
 
 use super::{
+	alias::Alias,
+	completion::{self, Completion},
+	cvar::{Cvar, Cvars, Error, Value},
 	distribute::Distribute,
 	history::History,
-	record::{Level, Record},
+	locale::{self, tr},
+	permission::{Permission, CHEATS},
+	pipe::{Filter, Pipe},
+	record::{Entry, Level, Record},
+	script,
+	timer::{Delay, Timers},
+	user,
 };
 
 
@@ -15,7 +29,21 @@ use super::{
 pub(crate) struct Actuator {
 	record:     Record,
 	history:    History,
+	cvars:      Cvars,
+	alias:      Alias,
+	queue: VecDeque<(
+		String,
+		usize,
+		usize,
+	)>,
+	sequence:   usize,
+	current:    usize,
+	timers:     Timers,
+	pipes:      Vec<Pipe>,
 	distribute: HashMap<String, Distribute>,
+	order:      Vec<String>,
+	inactive:   HashSet<String>,
+	base:       clap::Command,
 	matcher:    clap::Command,
 }
 
@@ -30,6 +58,28 @@ impl Actuator {
 		self.history.push(value);
 	}
 
+	pub fn get_history(&self, number: usize) -> Option<&String> {
+		self.history.get(number)
+	}
+
+	pub fn history_list(&self, quantity: Option<usize>) -> String {
+		self.history
+			.list(quantity)
+	}
+
+	pub fn search_history(
+		&self,
+		query: &str,
+		before: Option<usize>,
+	) -> Option<(
+		usize,
+		&String,
+	)> {
+		self.history.search(
+			query, before,
+		)
+	}
+
 	pub fn roll_back_history(&mut self) -> Option<&String> {
 		self.history.roll_back()
 	}
@@ -52,56 +102,512 @@ impl Actuator {
 			.push_empty(quantity)
 	}
 
-	pub fn replace_last_record(&mut self, value: &str, level: Level) {
+	pub fn update_record(&mut self, key: usize, value: &str, level: Level) {
+		self.record.update(
+			key, value, level,
+		);
+	}
+
+	pub fn forget_record(&mut self, key: usize) {
+		self.record.forget(key);
+	}
+
+	pub fn recorded(&mut self) -> Vec<Entry> {
+		self.record.recorded()
+	}
+
+	pub fn set_record_source(&mut self, source: &str) {
+		self.record
+			.set_source(source);
+	}
+
+	pub fn set_record_frame(&mut self, frame: u32) {
 		self.record
-			.replace_last(
-				value, level,
+			.set_frame(frame);
+	}
+
+	pub fn set_record_filter(&mut self, filter: Option<HashSet<Level>>) {
+		self.record
+			.set_filter(filter);
+	}
+
+	pub fn get_record_filter(&self) -> Option<&HashSet<Level>> {
+		self.record.get_filter()
+	}
+
+	pub fn visible_record(&self) -> Vec<&Entry> {
+		self.record.visible()
+	}
+
+	pub fn dump_record<P>(&self, path: P) -> io::Result<usize>
+	where P: AsRef<Path> {
+		let content = self
+			.record
+			.iter()
+			.map(
+				|v| {
+					format!(
+						"{} [{:?}] ({} #{}) {}\n",
+						v.timestamp, v.level, v.source, v.frame, v.message
+					)
+				},
+			)
+			.collect::<Vec<_>>();
+
+		fs::write(
+			path,
+			content.concat(),
+		)?;
+
+		Ok(content.len())
+	}
+}
+
+impl Actuator {
+	pub fn enqueue(&mut self, input: &str, depth: usize) {
+		self.append(
+			script::split(input)
+				.into_iter()
+				.map(
+					|v| {
+						(
+							v, depth,
+						)
+					},
+				)
+				.collect(),
+		);
+	}
+
+	pub fn prepend(&mut self, input: Vec<String>, depth: usize) {
+		for v in input.into_iter().rev() {
+			self.queue.push_front(
+				(
+					v, depth, self.current,
+				),
 			);
+		}
 	}
 
-	pub fn get_record(
-		&self,
-		index: &Option<usize>,
-		quantity: &usize,
-	) -> Iter<(
+	pub fn exec<P>(&mut self, path: P, depth: usize) -> io::Result<()>
+	where P: AsRef<Path> {
+		let input = script::read(path)?;
+
+		self.prepend(
+			input, depth,
+		);
+
+		Ok(())
+	}
+
+	pub fn dequeue(
+		&mut self,
+	) -> Option<(
 		String,
-		Level,
+		usize,
 	)> {
-		self.record.get(
-			index, quantity,
+		let mut blocked = self
+			.pipes
+			.iter()
+			.filter(|v| v.is_held())
+			.map(|v| v.sequence())
+			.collect::<HashSet<_>>();
+
+		let index = self
+			.queue
+			.iter()
+			.position(
+				|(input, _, sequence)| {
+					if blocked.contains(sequence) {
+						return false;
+					}
+
+					let bin = Shlex::new(input).next();
+
+					if self
+						.pipes
+						.iter()
+						.any(|v| v.is_held() && bin.as_deref() == Some(v.source()))
+					{
+						blocked.insert(*sequence);
+
+						return false;
+					}
+
+					true
+				},
+			)?;
+
+		let (input, depth, sequence) = self
+			.queue
+			.remove(index)?;
+
+		self.current = sequence;
+
+		Some(
+			(
+				input, depth,
+			),
+		)
+	}
+
+	fn append(
+		&mut self,
+		commands: Vec<(
+			String,
+			usize,
+		)>,
+	) {
+		self.sequence += 1;
+
+		for (input, depth) in commands {
+			self.queue.push_back(
+				(
+					input, depth, self.sequence,
+				),
+			);
+		}
+	}
+
+	pub fn is_pending(&self) -> bool {
+		!self.queue.is_empty() || self.timers.is_due()
+	}
+
+	pub fn is_idle(&self) -> bool {
+		self.queue.is_empty() && !self.timers.is_waiting() && self.pipes.is_empty()
+	}
+}
+
+impl Actuator {
+	pub fn begin_pipe(&mut self, filters: Vec<Filter>, source: &str) {
+		self.end_pipe(|v| v.source().eq(source));
+
+		self.record
+			.begin_capture(source);
+
+		self.pipes.push(
+			Pipe::new(
+				filters, source, self.current,
+			),
+		);
+	}
+
+	pub fn hold_pipe(&mut self, source: &str) {
+		for v in self
+			.pipes
+			.iter_mut()
+			.filter(|v| v.source().eq(source))
+		{
+			v.hold();
+		}
+	}
+
+	pub fn answer_pipe(&mut self, source: &str) {
+		self.end_pipe(|v| v.is_held() && v.source().eq(source));
+	}
+
+	pub fn release_pipe(&mut self) {
+		self.end_pipe(|v| !v.is_held());
+	}
+
+	pub fn advance_pipe(&mut self) -> bool {
+		self.end_pipe(|v| v.is_held() && v.advance())
+	}
+
+	fn end_pipe<F>(&mut self, mut f: F) -> bool
+	where F: FnMut(&mut Pipe) -> bool {
+		let mut ended = false;
+
+		let mut index = 0;
+
+		while index < self.pipes.len() {
+			if !f(&mut self.pipes[index]) {
+				index += 1;
+
+				continue;
+			}
+
+			let pipe = self.pipes.remove(index);
+
+			let lines = pipe.apply(
+				self.record
+					.end_capture(pipe.source()),
+			);
+
+			let source = self
+				.record
+				.source()
+				.to_string();
+
+			self.record
+				.set_source(pipe.source());
+
+			for (message, level) in lines {
+				self.record.push(
+					&message, level,
+				);
+			}
+
+			self.record
+				.set_source(&source);
+
+			ended = true;
+		}
+
+		ended
+	}
+}
+
+impl Actuator {
+	pub fn set_timer_clock(&mut self, frame: u32, elapsed: Duration) {
+		self.timers.set_clock(
+			frame, elapsed,
+		);
+	}
+
+	pub fn defer(&mut self, delay: Delay) {
+		let mut commands = vec![];
+
+		self.queue.retain(
+			|(input, depth, sequence)| {
+				if *sequence != self.current {
+					return true;
+				}
+
+				commands.push(
+					(
+						input.clone(),
+						*depth,
+					),
+				);
+
+				false
+			},
+		);
+
+		if !commands.is_empty() {
+			self.timers.insert(
+				delay, false, commands,
+			);
+		}
+	}
+
+	pub fn schedule(&mut self, delay: Delay, input: &str, repeat: bool) -> usize {
+		let commands = script::split(input)
+			.into_iter()
+			.map(
+				|v| {
+					(
+						v, 1,
+					)
+				},
+			)
+			.collect();
+
+		self.timers.insert(
+			delay, repeat, commands,
+		)
+	}
+
+	pub fn cancel_timer(&mut self, id: usize) -> bool {
+		self.timers.remove(id)
+	}
+
+	pub fn tick(&mut self) {
+		for commands in self.timers.due() {
+			self.append(commands);
+		}
+	}
+}
+
+impl Actuator {
+	pub fn expand_alias(&self, input: &str) -> Option<String> {
+		self.alias.expand(input)
+	}
+
+	pub fn insert_alias(&mut self, name: &str, value: &str) -> io::Result<()> {
+		self.alias.insert(
+			name, value,
+		)
+	}
+
+	pub fn remove_alias(&mut self, name: &str) -> io::Result<Option<String>> {
+		self.alias.remove(name)
+	}
+
+	pub fn alias_list(&self) -> String {
+		self.alias.list()
+	}
+}
+
+impl Actuator {
+	pub fn register_cvar(&mut self, cvar: Cvar) {
+		if cvar.is_command() {
+			self.base = self
+				.base
+				.clone()
+				.subcommand(
+					clap::Command::new(cvar.name().to_string())
+						.about(
+							cvar.get_about()
+								.to_string(),
+						)
+						.arg(clap::arg!([value] "new value")),
+				);
+		}
+
+		self.cvars
+			.register(cvar);
+
+		self.rebuild();
+	}
+
+	pub fn cvar(&self, name: &str) -> Option<&Cvar> {
+		self.cvars.get(name)
+	}
+
+	pub fn set_cvar(&mut self, name: &str, input: &str) -> Result<&Value, Error> {
+		self.cvars.set(
+			name, input,
 		)
 	}
 
-	pub fn record_len(&self) -> usize {
-		self.record.len()
+	pub fn reset_cvar(&mut self, name: &str) -> Result<&Value, Error> {
+		self.cvars.reset(name)
+	}
+
+	pub fn changed_cvar(
+		&mut self,
+	) -> Vec<(
+		String,
+		Value,
+	)> {
+		self.cvars.changed()
 	}
 
-	pub fn record_last_index(&self) -> usize {
-		self.record.last_index()
+	pub fn cvar_list(&self) -> String {
+		self.cvars
+			.iter()
+			.map(
+				|v| {
+					format!(
+						"{} = {} (default {})  {}",
+						v.name(),
+						v.get(),
+						v.get_default(),
+						tr!(v.get_about())
+					)
+				},
+			)
+			.collect::<Vec<_>>()
+			.join("\n")
 	}
 }
 
 impl Actuator {
 	pub fn register(&mut self, system: SystemId, matcher: clap::Command) {
+		if self
+			.distribute
+			.contains_key(matcher.get_name())
+		{
+			panic!("command does already exist");
+		}
+
+		self.replace(
+			system, matcher,
+		);
+	}
+
+	pub fn replace(&mut self, system: SystemId, matcher: clap::Command) -> Option<SystemId> {
 		let id = matcher
 			.get_name()
 			.to_string();
 
 		let matcher = matcher.subcommand(clap::Command::new("help"));
 
-		if let Some(_) = self.distribute.get(&id) {
-			panic!("command does already exist");
+		if !self.order.contains(&id) {
+			self.order
+				.push(id.clone());
 		}
 
-		self.matcher = self
-			.matcher
-			.clone()
-			.subcommand(matcher);
-
-		self.distribute.insert(
-			id,
-			Distribute::new(system),
+		let mut distribute = Distribute::new(
+			system, matcher,
 		);
+
+		if let Some(previous) = self.distribute.get(&id) {
+			distribute.set_permission(previous.permission());
+		}
+
+		let previous = self
+			.distribute
+			.insert(
+				id, distribute,
+			)
+			.map(|v| *v.system_id());
+
+		self.rebuild();
+
+		previous
+	}
+
+	pub fn unregister(&mut self, bin: &str) -> Option<SystemId> {
+		let distribute = self
+			.distribute
+			.remove(bin)?;
+
+		self.order
+			.retain(|v| v.ne(bin));
+		self.inactive
+			.remove(bin);
+
+		self.rebuild();
+
+		Some(*distribute.system_id())
+	}
+
+	pub fn set_active(&mut self, bin: &str, active: bool) {
+		let changed = if active {
+			self.inactive
+				.remove(bin)
+		}
+		else {
+			self.inactive
+				.insert(bin.to_string())
+		};
+
+		if changed {
+			self.rebuild();
+		}
+	}
+
+	pub fn set_permission(&mut self, bin: &str, permission: Permission) -> bool {
+		let Some(distribute) = self
+			.distribute
+			.get_mut(bin)
+		else {
+			return false;
+		};
+
+		distribute.set_permission(permission);
+
+		true
+	}
+
+	pub fn check_permission(&self, bin: &str) -> Result<(), Permission> {
+		let cheats = self
+			.cvars
+			.get(CHEATS)
+			.and_then(|v| v.get().as_bool())
+			.unwrap_or(false);
+
+		match self
+			.distribute
+			.get(bin)
+			.map(|v| v.permission())
+		{
+			| Some(permission) if !permission.allows(cheats) => Err(permission),
+			| _ => Ok(()),
+		}
 	}
 
 	pub fn push(&mut self, bin: &str, args: ArgMatches) -> Result<&SystemId, ()> {
@@ -130,35 +636,131 @@ impl Actuator {
 		}
 	}
 
+	pub fn expand(&self, input: &str) -> String {
+		script::expand(
+			input,
+			|name| {
+				match name {
+					| "time" => {
+						Some(
+							format!(
+								"{:.3}",
+								self.timers
+									.elapsed()
+									.as_secs_f64()
+							),
+						)
+					},
+					| "frame" => {
+						Some(
+							self.timers
+								.frame()
+								.to_string(),
+						)
+					},
+					| _ => {
+						self.cvars
+							.get(name)
+							.map(|v| v.get().as_text())
+							.or_else(|| env::var(name).ok())
+					},
+				}
+			},
+		)
+	}
+
 	pub fn matcher(
 		&mut self,
 		input: &str,
-	) -> Option<(
-		String,
-		ArgMatches,
-	)> {
-		let arguments = Shlex::new(input).collect::<Vec<_>>();
-
+	) -> Result<
+		(
+			String,
+			ArgMatches,
+		),
+		clap::Error,
+	> {
+		let arguments = match self.deferred(input) {
+			| Some(index) => {
+				Shlex::new(input)
+					.enumerate()
+					.map(
+						|(i, v)| {
+							if i == index {
+								v
+							}
+							else {
+								self.expand(&v)
+							}
+						},
+					)
+					.collect::<Vec<_>>()
+			},
+			| None => Shlex::new(&self.expand(input)).collect::<Vec<_>>(),
+		};
 
-		if let Ok(matcher) = self
+		let matcher = self
 			.matcher
-			.try_get_matches_from_mut(arguments)
-		{
-			if let Some((bin, args)) = matcher.subcommand() {
-				return Some(
-					(
-						bin.to_string(),
-						args.clone(),
-					),
-				)
-			}
+			.try_get_matches_from_mut(arguments)?;
+
+		if let Some((bin, args)) = matcher.subcommand() {
+			Ok(
+				(
+					bin.to_string(),
+					args.clone(),
+				),
+			)
+		}
+		else {
+			Err(
+				self.matcher.error(
+					ErrorKind::MissingSubcommand,
+					tr!("a command is required"),
+				),
+			)
 		}
+	}
+
+	fn deferred(&self, input: &str) -> Option<usize> {
+		let bin = Shlex::new(input).next()?;
 
-		None
+		self.matcher
+			.find_subcommand(&bin)?
+			.get_positionals()
+			.position(|v| v.get_id().eq("command"))
+			.map(|v| v + 1)
 	}
 
-	pub fn help(&mut self) -> String {
+	pub fn complete(&self, input: &str) -> Completion {
+		completion::complete(
+			&self.visible(),
+			input,
+			|bin, arg| {
+				match (
+					bin,
+					arg.get_id().as_str(),
+				) {
+					| ("set" | "get" | "reset", "name") => {
+						self.cvars
+							.iter()
+							.map(|v| v.name().to_string())
+							.collect()
+					},
+					| ("unalias", "name") => self.alias.names(),
+					| ("language", "code") => locale::available(),
+					| _ => vec![],
+				}
+			},
+		)
+	}
+
+	pub fn is_command(&self, bin: &str) -> bool {
 		self.matcher
+			.find_subcommand(bin)
+			.is_some()
+	}
+
+	pub fn help(&mut self) -> String {
+		self.visible()
 			.render_help()
 			.to_string()
 	}
@@ -176,6 +778,59 @@ impl Actuator {
 			String::from("")
 		}
 	}
+
+	pub fn set_language(&mut self, code: &str) -> Option<String> {
+		let code = locale::set(code)?;
+
+		self.rebuild();
+
+		Some(code)
+	}
+
+	fn rebuild(&mut self) {
+		self.matcher = self
+			.order
+			.iter()
+			.filter(
+				|v| {
+					!self
+						.inactive
+						.contains(*v)
+				},
+			)
+			.filter_map(|v| self.distribute.get(v))
+			.fold(
+				locale::command(self.base.clone()),
+				|matcher, v| matcher.subcommand(locale::command(v.matcher().clone())),
+			);
+	}
+
+	fn visible(&self) -> clap::Command {
+		self.distribute
+			.keys()
+			.filter(
+				|v| {
+					!self
+						.inactive
+						.contains(*v)
+				},
+			)
+			.filter(
+				|v| {
+					self.check_permission(v)
+						.is_err()
+				},
+			)
+			.fold(
+				self.matcher.clone(),
+				|matcher, bin| {
+					matcher.mut_subcommand(
+						bin,
+						|v| v.hide(true),
+					)
+				},
+			)
+	}
 }
 
 impl Default for Actuator {
@@ -196,12 +851,128 @@ impl Default for Actuator {
 				clap::Command::new("clear")
 					.alias("clean")
 					.about("clear the record"),
+			)
+			.subcommand(
+				clap::Command::new("condump")
+					.about("write the record to a file")
+					.arg(clap::arg!(<path> "output file")),
+			)
+			.subcommand(
+				clap::Command::new("history")
+					.about("list the command history, `!n` runs entry n")
+					.arg(
+						clap::arg!([count] "number of entries")
+							.value_parser(clap::value_parser!(usize)),
+					),
+			)
+			.subcommand(
+				clap::Command::new("filter")
+					.about("show only the given record levels, `off` shows everything")
+					.arg(
+						clap::arg!([level] ... "record level")
+							.value_parser(["off", "info", "success", "error", "warn", "display"]),
+					),
+			)
+			.subcommand(
+				clap::Command::new("exec")
+					.about("execute a script file")
+					.arg(clap::arg!(<path> "script file")),
+			)
+			.subcommand(
+				clap::Command::new("wait")
+					.about("delay the rest of the command sequence")
+					.arg(
+						clap::arg!([delay] "delay, e.g. 10f, 500ms or 1.5s, one frame by default")
+							.value_parser(Delay::parse),
+					),
+			)
+			.subcommand(
+				clap::Command::new("after")
+					.about("execute a command after a delay")
+					.arg(
+						clap::arg!(<delay> "delay, e.g. 10f, 500ms or 1.5s")
+							.value_parser(Delay::parse),
+					)
+					.arg(clap::arg!(<command> "command line")),
+			)
+			.subcommand(
+				clap::Command::new("every")
+					.about("execute a command repeatedly")
+					.arg(
+						clap::arg!(<delay> "interval, e.g. 10f, 500ms or 1.5s")
+							.value_parser(Delay::parse),
+					)
+					.arg(clap::arg!(<command> "command line")),
+			)
+			.subcommand(
+				clap::Command::new("cancel")
+					.about("cancel a timer created by after or every")
+					.arg(clap::arg!(<id> "timer id").value_parser(clap::value_parser!(usize))),
+			)
+			.subcommand(
+				clap::Command::new("alias")
+					.about("define a command alias")
+					.arg(clap::arg!(<name> "alias name"))
+					.arg(
+						clap::arg!(<command> "command line, `$1`..`$9` and `$*` take the arguments"),
+					),
+			)
+			.subcommand(
+				clap::Command::new("unalias")
+					.about("remove a command alias")
+					.arg(clap::arg!(<name> "alias name")),
+			)
+			.subcommand(clap::Command::new("aliaslist").about("list the command aliases"))
+			.subcommand(
+				clap::Command::new("set")
+					.about("set a console variable")
+					.arg(clap::arg!(<name> "variable name"))
+					.arg(clap::arg!(<value> "new value")),
+			)
+			.subcommand(
+				clap::Command::new("get")
+					.about("show a console variable")
+					.arg(clap::arg!(<name> "variable name")),
+			)
+			.subcommand(
+				clap::Command::new("reset")
+					.about("reset a console variable to its default")
+					.arg(clap::arg!(<name> "variable name")),
+			)
+			.subcommand(clap::Command::new("cvarlist").about("list the console variables"))
+			.subcommand(
+				clap::Command::new("language")
+					.about("change the language of the console")
+					.arg(clap::arg!([code] "language code, e.g. en or zh-CN")),
 			);
 
+		let mut cvars = Cvars::default();
+
+		cvars.register(
+			Cvar::new(
+				CHEATS,
+				Value::Bool(false),
+			)
+			.about("allow cheat commands"),
+		);
+
 		Self {
 			record,
-			history: Default::default(),
+			history: History::new(
+				user::path("history"),
+				500,
+			),
+			cvars,
+			alias: Alias::new(user::path("alias.cfg")),
+			queue: Default::default(),
+			sequence: 0,
+			current: 0,
+			timers: Default::default(),
+			pipes: Default::default(),
 			distribute: Default::default(),
+			order: Default::default(),
+			inactive: Default::default(),
+			base: matcher.clone(),
 			matcher,
 		}
 	}
@@ -0,0 +1,185 @@
+use std::{cell::Cell, io, path::PathBuf};
+
+use shlex::Shlex;
+
+use super::{script, table::Table};
+
+#[derive(Debug)]
+pub struct Alias {
+	table: Table,
+}
+
+impl Alias {
+	pub fn new(path: PathBuf) -> Self {
+		Self {
+			table: Table::new(
+				"alias",
+				path,
+				str::to_string,
+			),
+		}
+	}
+
+	pub fn get(&self, name: &str) -> Option<&String> {
+		self.table.get(name)
+	}
+
+	pub fn names(&self) -> Vec<String> {
+		self.table.keys()
+	}
+
+	pub fn insert(&mut self, name: &str, value: &str) -> io::Result<()> {
+		self.table.insert(
+			name, value,
+		)
+	}
+
+	pub fn remove(&mut self, name: &str) -> io::Result<Option<String>> {
+		self.table.remove(name)
+	}
+
+	pub fn expand(&self, input: &str) -> Option<String> {
+		let mut words = Shlex::new(input);
+
+		let value = self.get(&words.next()?)?;
+
+		let arguments = words.collect::<Vec<_>>();
+
+		let used = Cell::new(false);
+
+		let value = script::expand(
+			value,
+			|name| {
+				let value = match name {
+					| "*" => script::join(&arguments),
+					| _ => {
+						let index = name
+							.parse::<usize>()
+							.ok()
+							.filter(|v| *v > 0)?;
+
+						arguments
+							.get(index - 1)
+							.map(|v| script::quote(v))
+							.unwrap_or_default()
+					},
+				};
+
+				used.set(true);
+
+				Some(value)
+			},
+		);
+
+		if used.get() {
+			return Some(value);
+		}
+
+		Some(
+			[value, script::join(&arguments)]
+				.join(" ")
+				.trim()
+				.to_string(),
+		)
+	}
+
+	pub fn list(&self) -> String {
+		self.table.list()
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::Alias;
+	use crate::core::user;
+
+
+	fn alias() -> Alias {
+		let mut alias = Alias::new(user::scratch("alias-expand").join("alias.cfg"));
+
+		for (name, value) in [
+			(
+				"greet", "say hello",
+			),
+			(
+				"tp", "teleport $2 $1",
+			),
+			(
+				"all", "say $*; echo done",
+			),
+		] {
+			alias
+				.insert(
+					name, value,
+				)
+				.unwrap();
+		}
+
+		alias
+	}
+
+
+	#[test]
+	fn expand_arguments() {
+		let alias = alias();
+
+		assert_eq!(
+			alias.expand("greet"),
+			Some(String::from("say hello"))
+		);
+
+		assert_eq!(
+			alias.expand("greet 'big world'"),
+			Some(String::from("say hello 'big world'"))
+		);
+
+		assert_eq!(
+			alias.expand("tp 1 2"),
+			Some(String::from("teleport 2 1"))
+		);
+
+		assert_eq!(
+			alias.expand("tp 1"),
+			Some(String::from("teleport  1"))
+		);
+
+		assert_eq!(
+			alias.expand("all a 'b c'"),
+			Some(String::from("say a 'b c'; echo done"))
+		);
+
+		assert_eq!(
+			alias.expand("help"),
+			None
+		);
+	}
+
+	#[test]
+	fn save_and_load() {
+		let path = user::scratch("alias").join("alias.cfg");
+
+		let mut alias = Alias::new(path.clone());
+
+		alias
+			.insert(
+				"x", "get 'a b'; help",
+			)
+			.unwrap();
+
+		assert_eq!(
+			Alias::new(path.clone()).get("x"),
+			Some(&String::from("get 'a b'; help"))
+		);
+
+		alias
+			.remove("x")
+			.unwrap();
+
+		assert_eq!(
+			Alias::new(path).get("x"),
+			None
+		);
+	}
+}
@@ -0,0 +1,157 @@
+use clap::{Arg, Command};
+use shlex::Shlex;
+
+#[derive(Default, Debug)]
+pub struct Completion {
+	pub value:      String,
+	pub candidates: Vec<String>,
+}
+
+pub fn complete<F>(matcher: &Command, input: &str, values: F) -> Completion
+where F: Fn(&str, &Arg) -> Vec<String> {
+	let split = input
+		.rfind(char::is_whitespace)
+		.map(|i| i + 1)
+		.unwrap_or(0);
+
+	let (head, word) = input.split_at(split);
+
+	let mut command = matcher;
+	let mut positional = 0;
+	let mut pending: Option<&Arg> = None;
+
+	for token in Shlex::new(head) {
+		if pending.take().is_some() {
+			continue;
+		}
+
+		if let Some(name) = token.strip_prefix("--") {
+			pending = command
+				.get_arguments()
+				.find(|v| v.get_long() == Some(name))
+				.filter(
+					|v| {
+						v.get_action()
+							.takes_values()
+					},
+				);
+
+			continue;
+		}
+
+		if let Some(subcommand) = command.find_subcommand(&token) {
+			command = subcommand;
+			positional = 0;
+
+			continue;
+		}
+
+		positional += 1;
+	}
+
+	let bin = command.get_name();
+
+	let mut candidates = vec![];
+
+	if let Some(arg) = pending {
+		candidates.extend(possible_values(arg));
+		candidates.extend(
+			values(
+				bin, arg,
+			),
+		);
+	}
+	else if word.starts_with('-') {
+		candidates.extend(
+			command
+				.get_arguments()
+				.filter(|v| !v.is_hide_set())
+				.filter_map(|v| v.get_long())
+				.map(|v| format!("--{v}")),
+		);
+	}
+	else {
+		if positional == 0 {
+			for subcommand in command
+				.get_subcommands()
+				.filter(|v| !v.is_hide_set())
+			{
+				candidates.push(
+					subcommand
+						.get_name()
+						.to_string(),
+				);
+				candidates.extend(
+					subcommand
+						.get_all_aliases()
+						.map(|v| v.to_string()),
+				);
+			}
+		}
+
+		if let Some(arg) = command
+			.get_positionals()
+			.nth(positional)
+		{
+			candidates.extend(possible_values(arg));
+			candidates.extend(
+				values(
+					bin, arg,
+				),
+			);
+		}
+	}
+
+	candidates.retain(|v| v.starts_with(word));
+	candidates.sort();
+	candidates.dedup();
+
+	let value = match candidates.len() {
+		| 0 => input.to_string(),
+		| 1 => {
+			format!(
+				"{head}{} ",
+				candidates[0]
+			)
+		},
+		| _ => {
+			format!(
+				"{head}{}",
+				common_prefix(&candidates)
+			)
+		},
+	};
+
+	Completion { value, candidates }
+}
+
+fn possible_values(arg: &Arg) -> Vec<String> {
+	arg.get_possible_values()
+		.iter()
+		.filter(|v| !v.is_hide_set())
+		.map(|v| v.get_name().to_string())
+		.collect()
+}
+
+fn common_prefix(candidates: &[String]) -> &str {
+	let first = &candidates[0];
+
+	let length = candidates
+		.iter()
+		.skip(1)
+		.map(
+			|v| {
+				first
+					.char_indices()
+					.zip(v.chars())
+					.take_while(|((_, a), b)| a == b)
+					.last()
+					.map(|((i, a), _)| i + a.len_utf8())
+					.unwrap_or(0)
+			},
+		)
+		.min()
+		.unwrap_or(first.len());
+
+	&first[..length]
+}
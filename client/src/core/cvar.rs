@@ -0,0 +1,615 @@
+use std::{
+	collections::{btree_map::Values, BTreeMap},
+	fmt,
+};
+
+use super::locale::tr;
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Value {
+	Bool(bool),
+	Integer(i64),
+	Float(f64),
+	Text(String),
+}
+
+impl Value {
+	pub fn kind(&self) -> &'static str {
+		match self {
+			| Value::Bool(_) => "bool",
+			| Value::Integer(_) => "integer",
+			| Value::Float(_) => "float",
+			| Value::Text(_) => "text",
+		}
+	}
+
+	pub fn parse(&self, input: &str) -> Option<Self> {
+		let number = input
+			.parse::<f64>()
+			.ok()
+			.filter(|v| v.is_finite());
+
+		match self {
+			| Value::Bool(_) => {
+				match input {
+					| "true" | "on" => Some(Value::Bool(true)),
+					| "false" | "off" => Some(Value::Bool(false)),
+					| _ => {
+						number
+							.filter(|v| *v == 0.0 || *v == 1.0)
+							.map(|v| Value::Bool(v == 1.0))
+					},
+				}
+			},
+			| Value::Integer(_) => {
+				input
+					.parse()
+					.ok()
+					.or_else(
+						|| {
+							number
+								.filter(|v| v.fract() == 0.0)
+								.map(|v| v as i64)
+						},
+					)
+					.map(Value::Integer)
+			},
+			| Value::Float(_) => number.map(Value::Float),
+			| Value::Text(_) => Some(Value::Text(input.to_string())),
+		}
+	}
+
+	pub fn as_bool(&self) -> Option<bool> {
+		match self {
+			| Value::Bool(v) => Some(*v),
+			| Value::Integer(v) => Some(*v != 0),
+			| _ => None,
+		}
+	}
+
+	pub fn as_integer(&self) -> Option<i64> {
+		match self {
+			| Value::Bool(v) => Some(*v as i64),
+			| Value::Integer(v) => Some(*v),
+			| _ => None,
+		}
+	}
+
+	pub fn as_text(&self) -> String {
+		match self {
+			| Value::Text(v) => v.clone(),
+			| _ => self.to_string(),
+		}
+	}
+
+	pub fn as_float(&self) -> Option<f64> {
+		match self {
+			| Value::Integer(v) => Some(*v as f64),
+			| Value::Float(v) => Some(*v),
+			| _ => None,
+		}
+	}
+}
+
+impl fmt::Display for Value {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			| Value::Bool(v) => {
+				write!(
+					f,
+					"{}",
+					*v as u8
+				)
+			},
+			| Value::Integer(v) => {
+				write!(
+					f,
+					"{v}"
+				)
+			},
+			| Value::Float(v) => {
+				write!(
+					f,
+					"{v}"
+				)
+			},
+			| Value::Text(v) => {
+				write!(
+					f,
+					"\"{v}\""
+				)
+			},
+		}
+	}
+}
+
+
+
+#[derive(Debug)]
+pub enum Error {
+	Missing(String),
+	Invalid(
+		String,
+		&'static str,
+	),
+	Range(
+		String,
+		f64,
+		f64,
+	),
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			| Error::Missing(name) => {
+				write!(
+					f,
+					"{}",
+					tr!(
+						"variable `{name}` does not exist",
+						name = name
+					)
+				)
+			},
+			| Error::Invalid(name, kind) => {
+				write!(
+					f,
+					"{}",
+					tr!(
+						"variable `{name}` expects {kind}",
+						name = name,
+						kind = tr!(kind)
+					)
+				)
+			},
+			| Error::Range(name, min, max) => {
+				write!(
+					f,
+					"{}",
+					tr!(
+						"variable `{name}` must be between {min} and {max}",
+						name = name,
+						min = min,
+						max = max
+					)
+				)
+			},
+		}
+	}
+}
+
+
+
+#[derive(Clone, Debug)]
+pub struct Cvar {
+	name:    String,
+	about:   String,
+	default: Value,
+	value:   Value,
+	range: Option<(
+		f64,
+		f64,
+	)>,
+	command: bool,
+}
+
+impl Cvar {
+	pub fn new(name: &str, default: Value) -> Self {
+		Self {
+			name: name.to_string(),
+			about: String::new(),
+			value: default.clone(),
+			default,
+			range: None,
+			command: false,
+		}
+	}
+
+	pub fn about(mut self, about: &str) -> Self {
+		self.about = about.to_string();
+
+		self
+	}
+
+	pub fn range(mut self, min: f64, max: f64) -> Self {
+		self.range = Some(
+			(
+				min, max,
+			),
+		);
+
+		self
+	}
+
+	pub fn command(mut self) -> Self {
+		self.command = true;
+
+		self
+	}
+
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub fn get_about(&self) -> &str {
+		&self.about
+	}
+
+	pub fn get(&self) -> &Value {
+		&self.value
+	}
+
+	pub fn get_default(&self) -> &Value {
+		&self.default
+	}
+
+	pub fn is_command(&self) -> bool {
+		self.command
+	}
+
+	fn check(&self, value: &Value) -> Result<(), Error> {
+		if std::mem::discriminant(value) != std::mem::discriminant(&self.default) {
+			return Err(
+				Error::Invalid(
+					self.name.clone(),
+					self.default.kind(),
+				),
+			)
+		}
+
+		if let (Some((min, max)), Some(v)) = (
+			self.range,
+			value.as_float(),
+		) {
+			if v < min || v > max {
+				return Err(
+					Error::Range(
+						self.name.clone(),
+						min,
+						max,
+					),
+				)
+			}
+		}
+
+		Ok(())
+	}
+}
+
+
+
+#[derive(Default, Debug)]
+pub struct Cvars {
+	value:   BTreeMap<String, Cvar>,
+	changed: Vec<(
+		String,
+		Value,
+	)>,
+}
+
+impl Cvars {
+	pub fn register(&mut self, cvar: Cvar) {
+		if self
+			.value
+			.contains_key(cvar.name())
+		{
+			panic!("variable does already exist");
+		}
+
+		self.value.insert(
+			cvar.name().to_string(),
+			cvar,
+		);
+	}
+
+	pub fn get(&self, name: &str) -> Option<&Cvar> {
+		self.value.get(name)
+	}
+
+	pub fn iter(&self) -> Values<'_, String, Cvar> {
+		self.value.values()
+	}
+
+	pub fn set(&mut self, name: &str, input: &str) -> Result<&Value, Error> {
+		let value = self
+			.value
+			.get(name)
+			.ok_or_else(|| Error::Missing(name.to_string()))?;
+
+		let value = value
+			.default
+			.parse(input)
+			.ok_or_else(
+				|| {
+					Error::Invalid(
+						name.to_string(),
+						value.default.kind(),
+					)
+				},
+			)?;
+
+		self.assign(
+			name, value,
+		)
+	}
+
+	pub fn assign(&mut self, name: &str, value: Value) -> Result<&Value, Error> {
+		let cvar = self
+			.value
+			.get_mut(name)
+			.ok_or_else(|| Error::Missing(name.to_string()))?;
+
+		cvar.check(&value)?;
+
+		if cvar.value != value {
+			cvar.value = value;
+
+			self.changed.push(
+				(
+					name.to_string(),
+					cvar.value.clone(),
+				),
+			);
+		}
+
+		Ok(&cvar.value)
+	}
+
+	pub fn reset(&mut self, name: &str) -> Result<&Value, Error> {
+		let value = self
+			.value
+			.get(name)
+			.ok_or_else(|| Error::Missing(name.to_string()))?
+			.default
+			.clone();
+
+		self.assign(
+			name, value,
+		)
+	}
+
+	pub fn changed(
+		&mut self,
+	) -> Vec<(
+		String,
+		Value,
+	)> {
+		self.changed
+			.drain(..)
+			.collect()
+	}
+}
+
+
+
+
+#[cfg(test)]
+mod test {
+	use super::{Cvar, Cvars, Error, Value};
+
+
+	fn cvars() -> Cvars {
+		let mut cvars = Cvars::default();
+
+		cvars.register(
+			Cvar::new(
+				"fps",
+				Value::Bool(false),
+			),
+		);
+		cvars.register(
+			Cvar::new(
+				"input",
+				Value::Integer(0),
+			)
+			.range(
+				0.0, 2.0,
+			),
+		);
+		cvars.register(
+			Cvar::new(
+				"scale",
+				Value::Float(1.0),
+			)
+			.range(
+				0.5, 4.0,
+			),
+		);
+		cvars.register(
+			Cvar::new(
+				"name",
+				Value::Text(String::from("player")),
+			),
+		);
+
+		cvars
+	}
+
+
+	#[test]
+	fn parse_value() {
+		for (input, value) in [
+			(
+				"1", true,
+			),
+			(
+				"on", true,
+			),
+			(
+				"true", true,
+			),
+			(
+				"1.0", true,
+			),
+			(
+				"0", false,
+			),
+			(
+				"off", false,
+			),
+		] {
+			assert_eq!(
+				Value::Bool(false).parse(input),
+				Some(Value::Bool(value)),
+				"{input}"
+			);
+		}
+
+		for input in ["2", "yes", ""] {
+			assert_eq!(
+				Value::Bool(false).parse(input),
+				None,
+				"{input}"
+			);
+		}
+
+		assert_eq!(
+			Value::Integer(0).parse("2.0"),
+			Some(Value::Integer(2))
+		);
+
+		assert_eq!(
+			Value::Integer(0).parse("1.5"),
+			None
+		);
+
+		assert_eq!(
+			Value::Float(0.0).parse("1e3"),
+			Some(Value::Float(1000.0))
+		);
+
+		assert_eq!(
+			Value::Float(0.0).parse("inf"),
+			None
+		);
+
+		assert_eq!(
+			Value::Text(String::new())
+				.parse("a b")
+				.map(|v| v.to_string()),
+			Some(String::from("\"a b\""))
+		);
+	}
+
+	#[test]
+	fn set_in_range() {
+		let mut cvars = cvars();
+
+		assert_eq!(
+			cvars
+				.set("fps", "on")
+				.cloned()
+				.ok(),
+			Some(Value::Bool(true))
+		);
+
+		assert_eq!(
+			cvars
+				.set("input", "2")
+				.cloned()
+				.ok(),
+			Some(Value::Integer(2))
+		);
+
+		assert!(
+			matches!(
+				cvars.set("input", "3"),
+				Err(Error::Range(..))
+			)
+		);
+
+		assert!(
+			matches!(
+				cvars.set("scale", "0.1"),
+				Err(Error::Range(..))
+			)
+		);
+
+		assert!(
+			matches!(
+				cvars.set("input", "gamepad"),
+				Err(Error::Invalid(..))
+			)
+		);
+
+		assert!(
+			matches!(
+				cvars.set("nope", "1"),
+				Err(Error::Missing(_))
+			)
+		);
+
+		assert_eq!(
+			cvars
+				.set("input", "3")
+				.unwrap_err()
+				.to_string(),
+			"variable `input` must be between 0 and 2"
+		);
+
+		assert_eq!(
+			cvars
+				.set("fps", "2")
+				.unwrap_err()
+				.to_string(),
+			"variable `fps` expects bool"
+		);
+
+		assert_eq!(
+			cvars
+				.get("input")
+				.map(|v| v.get()),
+			Some(&Value::Integer(2))
+		);
+	}
+
+	#[test]
+	fn reset_and_changed() {
+		let mut cvars = cvars();
+
+		cvars
+			.set(
+				"scale", "2",
+			)
+			.unwrap();
+		cvars
+			.set(
+				"scale", "2",
+			)
+			.unwrap();
+		cvars
+			.reset("scale")
+			.unwrap();
+		cvars
+			.reset("fps")
+			.unwrap();
+
+		assert_eq!(
+			cvars.changed(),
+			vec![
+				(
+					String::from("scale"),
+					Value::Float(2.0)
+				),
+				(
+					String::from("scale"),
+					Value::Float(1.0)
+				),
+			]
+		);
+
+		assert!(
+			cvars
+				.changed()
+				.is_empty()
+		);
+
+		assert!(
+			matches!(
+				cvars.reset("nope"),
+				Err(Error::Missing(_))
+			)
+		);
+	}
+}
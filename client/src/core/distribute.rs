@@ -1,17 +1,23 @@
 use bevy::{ecs::system::SystemId, prelude::Resource};
 use clap::ArgMatches;
 
+use super::Permission;
+
 #[derive(Resource, Debug)]
 pub struct Distribute {
-	system:    SystemId,
-	arguments: Vec<ArgMatches>,
+	system:     SystemId,
+	matcher:    clap::Command,
+	permission: Permission,
+	arguments:  Vec<ArgMatches>,
 }
 
 impl Distribute {
-	pub fn new(system: SystemId) -> Self {
+	pub fn new(system: SystemId, matcher: clap::Command) -> Self {
 		Self {
 			system,
+			matcher,
 
+			permission: Default::default(),
 			arguments: Default::default(),
 		}
 	}
@@ -20,6 +26,18 @@ impl Distribute {
 		&self.system
 	}
 
+	pub fn matcher(&self) -> &clap::Command {
+		&self.matcher
+	}
+
+	pub fn permission(&self) -> Permission {
+		self.permission
+	}
+
+	pub fn set_permission(&mut self, permission: Permission) {
+		self.permission = permission;
+	}
+
 	pub fn read(&mut self) -> Vec<ArgMatches> {
 		self.arguments
 			.drain(..)
@@ -1,7 +1,7 @@
 use bevy::ecs::event::Event;
 use regex::Regex;
 
-use super::Level;
+use super::{cvar::Value, record::Entry, Level};
 
 
 
@@ -33,11 +33,36 @@ impl Execute {
 #[derive(Debug, Event)]
 pub(crate) enum Feedback {
 	Append(
+		String,
 		String,
 		Level,
 	),
 	Replace(
+		usize,
+		String,
 		String,
 		Level,
 	),
+	Done(String),
+}
+
+
+#[derive(Debug, Event)]
+pub(crate) struct Cancel;
+
+
+#[derive(Debug, Event)]
+pub(crate) struct Recorded(pub Entry);
+
+
+#[derive(Debug, Event)]
+pub(crate) struct CvarChanged {
+	pub name:  String,
+	pub value: Value,
+}
+
+impl CvarChanged {
+	pub fn is(&self, name: &str) -> bool {
+		self.name.eq(name)
+	}
 }
@@ -1,19 +1,82 @@
+use std::{fs, io, path::PathBuf};
+
 use bevy::prelude::Resource;
 
 #[derive(Resource, Default, Debug)]
 pub struct History {
+	max:   usize,
+	path:  PathBuf,
 	index: Option<usize>,
 	value: Vec<String>,
 }
 
 impl History {
-	pub fn push(&mut self, value: &str) {
-		let value = value.trim();
+	pub fn new(path: PathBuf, max: usize) -> Self {
+		let mut history = Self {
+			max,
+			path,
+			..Default::default()
+		};
+
+		history.load();
 
+		history
+	}
+
+	pub fn push(&mut self, value: &str) {
 		self.index = None;
 
+		if self.append(value) {
+			let _ = self.save();
+		}
+	}
+
+	pub fn get(&self, number: usize) -> Option<&String> {
 		self.value
-			.push(value.to_string());
+			.get(number.checked_sub(1)?)
+	}
+
+	pub fn list(&self, quantity: Option<usize>) -> String {
+		let skip = quantity.map_or(
+			0,
+			|v| {
+				self.value
+					.len()
+					.saturating_sub(v)
+			},
+		);
+
+		self.value
+			.iter()
+			.enumerate()
+			.skip(skip)
+			.map(
+				|(i, v)| {
+					format!(
+						"{:>5}  {v}",
+						i + 1
+					)
+				},
+			)
+			.collect::<Vec<_>>()
+			.join("\n")
+	}
+
+	pub fn search(
+		&self,
+		query: &str,
+		before: Option<usize>,
+	) -> Option<(
+		usize,
+		&String,
+	)> {
+		let end = before.unwrap_or(self.value.len());
+
+		self.value[..end.min(self.value.len())]
+			.iter()
+			.enumerate()
+			.rev()
+			.find(|(_, v)| v.contains(query))
 	}
 
 	pub fn roll_back(&mut self) -> Option<&String> {
@@ -53,4 +116,199 @@ impl History {
 			self.value.get(index)
 		}
 	}
+
+	fn append(&mut self, value: &str) -> bool {
+		let value = value.trim();
+
+		if value.is_empty() {
+			return false;
+		}
+
+		if self
+			.value
+			.last()
+			.is_some_and(|v| v.eq(value))
+		{
+			return false;
+		}
+
+		self.value
+			.push(value.to_string());
+
+		if self.value.len() > self.max {
+			self.value.remove(0);
+		}
+
+		true
+	}
+
+	fn load(&mut self) {
+		let Ok(content) = fs::read_to_string(&self.path)
+		else {
+			return;
+		};
+
+		for v in content.lines() {
+			self.append(v);
+		}
+	}
+
+	fn save(&self) -> io::Result<()> {
+		if let Some(directory) = self
+			.path
+			.parent()
+			.filter(|v| !v.as_os_str().is_empty())
+		{
+			fs::create_dir_all(directory)?;
+		}
+
+		let content = self
+			.value
+			.iter()
+			.map(|v| format!("{v}\n"))
+			.collect::<String>();
+
+		fs::write(
+			&self.path, content,
+		)
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::History;
+	use crate::core::user;
+
+
+	fn history(name: &str, max: usize) -> History {
+		History::new(
+			user::scratch(name).join("history"),
+			max,
+		)
+	}
+
+
+	#[test]
+	fn push_and_persist() {
+		let mut history = history(
+			"history-push", 3,
+		);
+
+		for v in ["a", " a ", "", "b", "c", "d"] {
+			history.push(v);
+		}
+
+		assert_eq!(
+			history.list(None),
+			"    1  b\n    2  c\n    3  d"
+		);
+
+		assert_eq!(
+			history.list(Some(1)),
+			"    3  d"
+		);
+
+		assert_eq!(
+			history.get(1),
+			Some(&String::from("b"))
+		);
+
+		assert_eq!(
+			history.get(0),
+			None
+		);
+
+		assert_eq!(
+			History::new(
+				user::path("history"),
+				3
+			)
+			.list(None),
+			history.list(None)
+		);
+	}
+
+	#[test]
+	fn roll() {
+		let mut history = history(
+			"history-roll", 10,
+		);
+
+		history.push("a");
+		history.push("b");
+
+		assert_eq!(
+			history.roll_back(),
+			Some(&String::from("b"))
+		);
+
+		assert_eq!(
+			history.roll_back(),
+			Some(&String::from("a"))
+		);
+
+		assert_eq!(
+			history.roll_back(),
+			Some(&String::from("a"))
+		);
+
+		assert_eq!(
+			history.roll_forward(),
+			Some(&String::from("b"))
+		);
+
+		assert_eq!(
+			history.roll_forward(),
+			None
+		);
+
+		history.roll_back();
+		history.push("c");
+
+		assert_eq!(
+			history.roll_back(),
+			Some(&String::from("c"))
+		);
+	}
+
+	#[test]
+	fn search() {
+		let mut history = history(
+			"history-search", 10,
+		);
+
+		for v in ["set a 1", "get a", "set b 2"] {
+			history.push(v);
+		}
+
+		assert_eq!(
+			history.search("set", None),
+			Some(
+				(
+					2,
+					&String::from("set b 2")
+				)
+			)
+		);
+
+		assert_eq!(
+			history.search(
+				"set",
+				Some(2)
+			),
+			Some(
+				(
+					0,
+					&String::from("set a 1")
+				)
+			)
+		);
+
+		assert_eq!(
+			history.search("nope", None),
+			None
+		);
+	}
 }
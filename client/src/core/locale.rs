@@ -0,0 +1,486 @@
+use std::{
+	collections::HashMap,
+	fmt, fs,
+	sync::{LazyLock, RwLock},
+};
+
+use super::user;
+
+
+
+pub const ENGLISH: &str = "en";
+
+const DIRECTORY: &str = "locale";
+
+const BUILTIN: &[(
+	&str,
+	&str,
+)] = &[(
+	"zh-CN",
+	include_str!("../../locale/zh-CN.ron"),
+)];
+
+
+static LOCALE: LazyLock<RwLock<Locale>> = LazyLock::new(|| RwLock::new(Locale::load()));
+
+
+#[derive(Debug)]
+struct Locale {
+	current: String,
+	value:   HashMap<String, HashMap<String, String>>,
+}
+
+impl Locale {
+	fn load() -> Self {
+		let mut value = HashMap::<String, HashMap<String, String>>::new();
+
+		for (code, content) in BUILTIN {
+			if let Ok(messages) = ron::from_str::<HashMap<String, String>>(content) {
+				value.insert(
+					code.to_string(),
+					messages,
+				);
+			}
+		}
+
+		if let Ok(entries) = fs::read_dir(user::path(DIRECTORY)) {
+			for path in entries
+				.flatten()
+				.map(|v| v.path())
+				.filter(|v| v.extension() == Some("ron".as_ref()))
+			{
+				let Some(code) = path
+					.file_stem()
+					.and_then(|v| v.to_str())
+				else {
+					continue;
+				};
+
+				let Some(messages) = fs::read_to_string(&path)
+					.ok()
+					.and_then(|v| ron::from_str::<HashMap<String, String>>(&v).ok())
+				else {
+					continue;
+				};
+
+				value
+					.entry(code.to_string())
+					.or_default()
+					.extend(messages);
+			}
+		}
+
+		Self {
+			current: String::from(ENGLISH),
+			value,
+		}
+	}
+
+	fn find(&self, code: &str) -> Option<String> {
+		let code = code.replace(
+			'_', "-",
+		);
+
+		if code.eq_ignore_ascii_case(ENGLISH) {
+			return Some(String::from(ENGLISH));
+		}
+
+		self.value
+			.keys()
+			.find(|v| v.eq_ignore_ascii_case(&code))
+			.cloned()
+	}
+
+	fn translate(
+		&self,
+		key: &str,
+		args: &[(
+			&str,
+			&dyn fmt::Display,
+		)],
+	) -> String {
+		let mut value = self
+			.value
+			.get(&self.current)
+			.and_then(|v| v.get(key))
+			.cloned()
+			.unwrap_or_else(|| key.to_string());
+
+		for (name, arg) in args {
+			value = value.replace(
+				&format!("{{{name}}}"),
+				&arg.to_string(),
+			);
+		}
+
+		value
+	}
+
+	fn command(&self, mut command: clap::Command) -> clap::Command {
+		if self.current.eq(ENGLISH) {
+			return command;
+		}
+
+		if let Some(about) = command.get_about() {
+			let about = self.translate(
+				&about.to_string(),
+				&[],
+			);
+
+			command = command.about(about);
+		}
+
+		let arguments = command
+			.get_arguments()
+			.map(|v| v.get_id().clone())
+			.collect::<Vec<_>>();
+
+		for id in arguments {
+			command = command.mut_arg(
+				id,
+				|arg| {
+					let heading = if arg.is_positional() {
+						"Arguments"
+					}
+					else {
+						"Options"
+					};
+
+					let arg = arg.help_heading(
+						self.translate(
+							heading,
+							&[],
+						),
+					);
+
+					match arg
+						.get_help()
+						.map(|v| v.to_string())
+					{
+						| Some(help) => {
+							arg.help(
+								self.translate(
+									&help,
+									&[],
+								),
+							)
+						},
+						| None => arg,
+					}
+				},
+			);
+		}
+
+		if !command.is_disable_help_flag_set() {
+			command = command
+				.disable_help_flag(true)
+				.arg(
+					clap::Arg::new("help")
+						.short('h')
+						.long("help")
+						.action(clap::ArgAction::Help)
+						.help_heading(
+							self.translate(
+								"Options",
+								&[],
+							),
+						)
+						.help(
+							self.translate(
+								"Print help",
+								&[],
+							),
+						),
+				);
+		}
+
+		let subcommands = command
+			.get_subcommands()
+			.map(|v| v.get_name().to_string())
+			.collect::<Vec<_>>();
+
+		for name in subcommands {
+			command = command.mut_subcommand(
+				name,
+				|v| self.command(v),
+			);
+		}
+
+		command
+			.subcommand_help_heading(
+				self.translate(
+					"Commands",
+					&[],
+				),
+			)
+			.help_template(
+				format!(
+					"{{before-help}}{{about-with-newline}}\n{}: {{usage}}\n\n{{all-args}}{{after-help}}",
+					self.translate(
+						"Usage",
+						&[],
+					)
+				),
+			)
+	}
+}
+
+
+pub fn current() -> String {
+	LOCALE
+		.read()
+		.map(|v| v.current.clone())
+		.unwrap_or_else(|_| String::from(ENGLISH))
+}
+
+pub fn available() -> Vec<String> {
+	let Ok(locale) = LOCALE.read()
+	else {
+		return vec![String::from(ENGLISH)];
+	};
+
+	let mut value = locale
+		.value
+		.keys()
+		.cloned()
+		.chain([String::from(ENGLISH)])
+		.collect::<Vec<_>>();
+
+	value.sort();
+	value.dedup();
+
+	value
+}
+
+pub fn set(code: &str) -> Option<String> {
+	let mut locale = LOCALE.write().ok()?;
+
+	let code = locale.find(code)?;
+
+	locale.current = code.clone();
+
+	Some(code)
+}
+
+pub fn translate(
+	key: &str,
+	args: &[(
+		&str,
+		&dyn fmt::Display,
+	)],
+) -> String {
+	match LOCALE.read() {
+		| Ok(locale) => {
+			locale.translate(
+				key, args,
+			)
+		},
+		| Err(_) => key.to_string(),
+	}
+}
+
+pub fn command(command: clap::Command) -> clap::Command {
+	match LOCALE.read() {
+		| Ok(locale) => locale.command(command),
+		| Err(_) => command,
+	}
+}
+
+
+macro_rules! tr {
+	($key:expr $(,)?) => {
+		$crate::core::locale::translate(
+			$key,
+			&[],
+		)
+	};
+	($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+		$crate::core::locale::translate(
+			$key,
+			&[$((
+				stringify!($name),
+				&$value as &dyn ::std::fmt::Display,
+			)),+],
+		)
+	};
+}
+
+pub(crate) use tr;
+
+
+
+#[cfg(test)]
+mod test {
+	use std::fs;
+
+	use super::{Locale, ENGLISH};
+	use crate::core::user;
+
+
+	fn locale(code: &str) -> Locale {
+		user::scratch(&format!("locale-{code}"));
+
+		let mut locale = Locale::load();
+
+		locale.current = locale
+			.find(code)
+			.unwrap();
+
+		locale
+	}
+
+
+	#[test]
+	fn load_catalog() {
+		let directory = user::scratch("locale-load").join("locale");
+
+		fs::create_dir_all(&directory).unwrap();
+
+		for (name, content) in [
+			(
+				"zh-CN.ron",
+				r#"{ "unknown command": "没有这个命令" }"#,
+			),
+			(
+				"fr.ron",
+				r#"{ "Usage": "Utilisation" }"#,
+			),
+			(
+				"xx.ron", "not ron",
+			),
+			(
+				"de.txt",
+				r#"{ "Usage": "Verwendung" }"#,
+			),
+		] {
+			fs::write(
+				directory.join(name),
+				content,
+			)
+			.unwrap();
+		}
+
+		let mut locale = Locale::load();
+
+		let mut codes = locale
+			.value
+			.keys()
+			.cloned()
+			.collect::<Vec<_>>();
+
+		codes.sort();
+
+		assert_eq!(
+			codes,
+			vec!["fr", "zh-CN"]
+		);
+
+		assert_eq!(
+			locale.find("zh_cn"),
+			Some(String::from("zh-CN"))
+		);
+		assert_eq!(
+			locale.find("EN"),
+			Some(String::from(ENGLISH))
+		);
+		assert_eq!(
+			locale.find("de"),
+			None
+		);
+
+		locale.current = String::from("zh-CN");
+
+		assert_eq!(
+			locale.translate(
+				"unknown command",
+				&[]
+			),
+			"没有这个命令"
+		);
+		assert_eq!(
+			locale.translate(
+				"missing argument",
+				&[]
+			),
+			"缺少参数"
+		);
+	}
+
+	#[test]
+	fn translate_fallback() {
+		let locale = locale("zh-CN");
+
+		assert_eq!(
+			locale.translate(
+				"bad argument: {bin}: {reason}",
+				&[("bin", &"ent"), ("reason", &1)]
+			),
+			"参数错误：ent：1"
+		);
+
+		assert_eq!(
+			locale.translate(
+				"no such key {name}",
+				&[("name", &"x")]
+			),
+			"no such key x"
+		);
+
+		let locale = self::locale(ENGLISH);
+
+		assert_eq!(
+			locale.translate(
+				"unknown command",
+				&[]
+			),
+			"unknown command"
+		);
+	}
+
+	#[test]
+	fn translate_command() {
+		let command = clap::Command::new("")
+			.no_binary_name(true)
+			.subcommand(
+				clap::Command::new("exec")
+					.about("execute a script file")
+					.arg(clap::arg!(<path> "script file")),
+			);
+
+		let mut english = locale(ENGLISH).command(command.clone());
+
+		assert!(
+			english
+				.render_help()
+				.to_string()
+				.contains("execute a script file")
+		);
+
+		let mut chinese = locale("zh-CN").command(command);
+
+		let exec = chinese
+			.find_subcommand_mut("exec")
+			.unwrap();
+
+		assert_eq!(
+			exec.get_about()
+				.map(|v| v.to_string()),
+			Some(String::from("执行脚本文件"))
+		);
+
+		let help = exec
+			.render_help()
+			.to_string();
+
+		assert!(help.contains("用法"));
+		assert!(help.contains("脚本文件"));
+		assert!(help.contains("显示帮助"));
+
+		assert!(
+			chinese
+				.render_help()
+				.to_string()
+				.contains("命令")
+		);
+	}
+}
@@ -0,0 +1,102 @@
+use std::{
+	fs::{self, File, OpenOptions},
+	io::{self, Write},
+	path::PathBuf,
+};
+
+use bevy::prelude::Resource;
+
+use super::{record::Entry, user};
+
+const MAX_SIZE: u64 = 1024 * 1024;
+
+const MAX_FILES: usize = 5;
+
+
+
+#[derive(Resource, Debug)]
+pub struct LogFile {
+	path: PathBuf,
+	file: Option<File>,
+}
+
+impl LogFile {
+	pub fn new(path: PathBuf) -> Self {
+		Self { path, file: None }
+	}
+
+	pub fn write(&mut self, entry: &Entry) {
+		if self
+			.append(entry)
+			.is_err()
+		{
+			self.file = None;
+		}
+	}
+
+	fn append(&mut self, entry: &Entry) -> io::Result<()> {
+		let file = self.open()?;
+
+		writeln!(
+			file,
+			"{} [{:?}] ({} #{}) {}",
+			entry.timestamp, entry.level, entry.source, entry.frame, entry.message
+		)?;
+
+		if file.metadata()?.len() > MAX_SIZE {
+			self.rotate()?;
+		}
+
+		Ok(())
+	}
+
+	fn open(&mut self) -> io::Result<&mut File> {
+		if self.file.is_none() {
+			if let Some(directory) = self.path.parent() {
+				fs::create_dir_all(directory)?;
+			}
+
+			self.file = Some(
+				OpenOptions::new()
+					.create(true)
+					.append(true)
+					.open(&self.path)?,
+			);
+		}
+
+		self.file
+			.as_mut()
+			.ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+	}
+
+	fn rotate(&mut self) -> io::Result<()> {
+		self.file = None;
+
+		for i in (1..MAX_FILES).rev() {
+			let from = self.rotated(i);
+
+			if from.exists() {
+				fs::rename(
+					from,
+					self.rotated(i + 1),
+				)?;
+			}
+		}
+
+		fs::rename(
+			&self.path,
+			self.rotated(1),
+		)
+	}
+
+	fn rotated(&self, index: usize) -> PathBuf {
+		self.path
+			.with_extension(format!("{index}.log"))
+	}
+}
+
+impl Default for LogFile {
+	fn default() -> Self {
+		Self::new(user::path("logs/console.log"))
+	}
+}
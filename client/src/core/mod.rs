@@ -1,46 +1,298 @@
 mod actuator;
+mod alias;
+mod completion;
+mod cvar;
 mod distribute;
 mod history;
+mod logfile;
+mod permission;
+mod pipe;
 mod record;
+mod task;
+mod timer;
+mod timestamp;
 
 pub mod event;
+pub mod locale;
+pub mod script;
+pub mod table;
+pub mod user;
+
+use std::{fmt, future::Future, path::Path};
 
 pub(crate) use actuator::Actuator;
 use bevy::{
-	app::{App, AppExit, Update},
+	app::{App, AppExit, First, Startup, Update},
+	core::FrameCount,
 	ecs::{
-		event::{Event, EventReader, EventWriter},
+		event::{Event, EventReader, EventRegistry, EventWriter, Events},
 		schedule::common_conditions,
-		system::{Commands, ResMut},
+		system::{Commands, Res, ResMut, SystemId},
+		world::World,
+	},
+	prelude::{Condition, IntoSystemConfigs},
+	state::{
+		condition,
+		state::{State, States},
 	},
-	prelude::IntoSystemConfigs,
+	time::{Time, TimeSystem},
 };
-use clap::ArgMatches;
-use event::{Execute, Feedback, Refresh};
-pub use record::Level;
+use clap::{error::ErrorKind, ArgMatches};
+pub use cvar::{Cvar, Value};
+use event::{Cancel, CvarChanged, Execute, Feedback, Recorded, Refresh};
+use locale::tr;
+use logfile::LogFile;
+pub use permission::Permission;
+use pipe::Filter;
+pub use record::{Entry, Level, SOURCE};
+pub use task::{Progress, Tasks};
+use timer::Delay;
+
+
+
+const AUTOEXEC: &str = "autoexec.cfg";
+
+const MAX_DEPTH: usize = 16;
+
+
 
 
+pub trait CommandEvent {
+	type Event: Event;
 
+	fn into_event(self) -> Result<Self::Event, String>;
+}
+
+impl<T> CommandEvent for Option<T>
+where T: Event
+{
+	type Event = T;
+
+	fn into_event(self) -> Result<T, String> {
+		self.ok_or_else(|| tr!("invalid argument"))
+	}
+}
+
+impl<T, E> CommandEvent for Result<T, E>
+where
+	T: Event,
+	E: fmt::Display,
+{
+	type Event = T;
+
+	fn into_event(self) -> Result<T, String> {
+		self.map_err(|v| v.to_string())
+	}
+}
 
 pub trait AddCommandEvent {
-	fn add_command_event<T, F>(&mut self, mather: clap::Command, f: F) -> &mut Self
+	fn add_command_event<R, F>(&mut self, matcher: clap::Command, f: F) -> &mut Self
+	where
+		R: CommandEvent,
+		F: Fn(ArgMatches) -> R + Send + Sync + 'static;
+
+	fn replace_command_event<R, F>(&mut self, matcher: clap::Command, f: F) -> &mut Self
+	where
+		R: CommandEvent,
+		F: Fn(ArgMatches) -> R + Send + Sync + 'static;
+}
+
+impl AddCommandEvent for World {
+	fn add_command_event<R, F>(&mut self, matcher: clap::Command, f: F) -> &mut Self
+	where
+		R: CommandEvent,
+		F: Fn(ArgMatches) -> R + Send + Sync + 'static,
+	{
+		command_event(
+			self, matcher, f, false,
+		);
+
+		self
+	}
+
+	fn replace_command_event<R, F>(&mut self, matcher: clap::Command, f: F) -> &mut Self
 	where
-		T: Event,
-		F: Fn(ArgMatches) -> Option<T> + Send + Sync + 'static;
+		R: CommandEvent,
+		F: Fn(ArgMatches) -> R + Send + Sync + 'static,
+	{
+		command_event(
+			self, matcher, f, true,
+		);
+
+		self
+	}
 }
 
 impl AddCommandEvent for App {
-	fn add_command_event<T, F>(&mut self, mut matcher: clap::Command, f: F) -> &mut Self
+	fn add_command_event<R, F>(&mut self, matcher: clap::Command, f: F) -> &mut Self
+	where
+		R: CommandEvent,
+		F: Fn(ArgMatches) -> R + Send + Sync + 'static,
+	{
+		self.world_mut()
+			.add_command_event(
+				matcher, f,
+			);
+
+		self
+	}
+
+	fn replace_command_event<R, F>(&mut self, matcher: clap::Command, f: F) -> &mut Self
+	where
+		R: CommandEvent,
+		F: Fn(ArgMatches) -> R + Send + Sync + 'static,
+	{
+		self.world_mut()
+			.replace_command_event(
+				matcher, f,
+			);
+
+		self
+	}
+}
+
+fn command_event<R, F>(world: &mut World, matcher: clap::Command, f: F, replace: bool)
+where
+	R: CommandEvent,
+	F: Fn(ArgMatches) -> R + Send + Sync + 'static,
+{
+	if !world.contains_resource::<Actuator>() {
+		panic!("missing plugin");
+	}
+
+	if !world.contains_resource::<Events<R::Event>>() {
+		EventRegistry::register_event::<R::Event>(world);
+	}
+
+	let bin = matcher
+		.get_name()
+		.to_string();
+
+	let system = world.register_system(
+		move |mut event: EventWriter<R::Event>,
+		      mut actuator: ResMut<Actuator>,
+		      mut refresh: EventWriter<Refresh>| {
+			for v in actuator.read(&bin) {
+				match f(v).into_event() {
+					| Ok(res) => {
+						event.send(res);
+					},
+					| Err(reason) => {
+						actuator.set_record_source(&bin);
+						actuator.push_record(
+							&tr!(
+								"bad argument: {bin}: {reason}",
+								bin = bin,
+								reason = reason
+							),
+							Level::Error,
+						);
+						actuator.answer_pipe(&bin);
+
+						refresh.send(Refresh);
+					},
+				}
+			}
+		},
+	);
+
+	register_command(
+		world, system, matcher, replace,
+	);
+}
+
+fn register_command(world: &mut World, system: SystemId, matcher: clap::Command, replace: bool) {
+	let Some(mut actuator) = world.get_resource_mut::<Actuator>()
+	else {
+		return;
+	};
+
+	let matcher = matcher
+		.disable_help_flag(true)
+		.disable_help_subcommand(true);
+
+	if !replace {
+		actuator.register(
+			system, matcher,
+		);
+
+		return;
+	}
+
+	if let Some(previous) = actuator.replace(
+		system, matcher,
+	) {
+		let _ = world.remove_system(previous);
+	}
+}
+
+pub trait RemoveCommand {
+	fn remove_command(&mut self, bin: &str) -> &mut Self;
+}
+
+impl RemoveCommand for World {
+	fn remove_command(&mut self, bin: &str) -> &mut Self {
+		let previous = self
+			.get_resource_mut::<Actuator>()
+			.and_then(|mut v| v.unregister(bin));
+
+		if let Some(previous) = previous {
+			let _ = self.remove_system(previous);
+		}
+
+		self
+	}
+}
+
+impl RemoveCommand for App {
+	fn remove_command(&mut self, bin: &str) -> &mut Self {
+		self.world_mut()
+			.remove_command(bin);
+
+		self
+	}
+}
+
+pub trait ScopeCommand {
+	fn scope_command<S: States>(&mut self, bin: &str, state: S) -> &mut Self;
+}
+
+impl ScopeCommand for App {
+	fn scope_command<S: States>(&mut self, bin: &str, state: S) -> &mut Self {
+		let bin = bin.to_string();
+
+		self.add_systems(
+			Update,
+			(move |current: Res<State<S>>, mut actuator: ResMut<Actuator>| {
+				actuator.set_active(
+					&bin,
+					current.get().eq(&state),
+				);
+			})
+			.run_if(condition::state_changed::<S>),
+		);
+
+		self
+	}
+}
+
+pub trait AddCommandTask {
+	fn add_command_task<R, F>(&mut self, matcher: clap::Command, f: F) -> &mut Self
+	where
+		R: Future<Output = Result<String, String>> + Send + 'static,
+		F: Fn(ArgMatches, Progress) -> R + Send + Sync + 'static;
+}
+
+impl AddCommandTask for App {
+	fn add_command_task<R, F>(&mut self, matcher: clap::Command, f: F) -> &mut Self
 	where
-		T: Event,
-		F: Fn(ArgMatches) -> Option<T> + Send + Sync + 'static,
+		R: Future<Output = Result<String, String>> + Send + 'static,
+		F: Fn(ArgMatches, Progress) -> R + Send + Sync + 'static,
 	{
 		if !self.is_plugin_added::<Plugin>() {
 			panic!("missing plugin");
 		}
 
-		self.add_event::<T>();
-
 		let world = self.world_mut();
 
 		let bin = matcher
@@ -48,22 +300,71 @@ impl AddCommandEvent for App {
 			.to_string();
 
 		let system = world.register_system(
-			move |mut event: EventWriter<T>, mut actuator: ResMut<Actuator>| {
+			move |mut actuator: ResMut<Actuator>, mut tasks: ResMut<Tasks>| {
 				for v in actuator.read(&bin) {
-					if let Some(res) = f(v) {
-						event.send(res);
-					};
+					let progress = Progress::default();
+
+					tasks.spawn(
+						&bin,
+						progress.clone(),
+						f(
+							v, progress,
+						),
+					);
 				}
 			},
 		);
 
-		if let Some(mut actuator) = world.get_resource_mut::<Actuator>() {
-			matcher = matcher.disable_help_flag(true);
-			matcher = matcher.disable_help_subcommand(true);
+		register_command(
+			world, system, matcher, false,
+		);
+
+		self
+	}
+}
+
+pub trait SetPermission {
+	fn set_permission(&mut self, bin: &str, permission: Permission) -> &mut Self;
+}
 
-			actuator.register(
-				system, matcher,
-			);
+impl SetPermission for App {
+	fn set_permission(&mut self, bin: &str, permission: Permission) -> &mut Self {
+		if let Some(mut actuator) = self
+			.world_mut()
+			.get_resource_mut::<Actuator>()
+		{
+			if !actuator.set_permission(
+				bin, permission,
+			) {
+				actuator.push_record(
+					&tr!(
+						"cannot set the permission of `{bin}`, the command does not exist",
+						bin = bin
+					),
+					Level::Warn,
+				);
+			}
+		};
+
+		self
+	}
+}
+
+pub trait AddCvar {
+	fn add_cvar(&mut self, cvar: Cvar) -> &mut Self;
+}
+
+impl AddCvar for App {
+	fn add_cvar(&mut self, cvar: Cvar) -> &mut Self {
+		if !self.is_plugin_added::<Plugin>() {
+			panic!("missing plugin");
+		}
+
+		if let Some(mut actuator) = self
+			.world_mut()
+			.get_resource_mut::<Actuator>()
+		{
+			actuator.register_cvar(cvar);
 		};
 
 		self
@@ -75,19 +376,44 @@ pub struct Plugin;
 impl bevy::app::Plugin for Plugin {
 	fn build(&self, app: &mut App) {
 		app.init_resource::<Actuator>();
+		app.init_resource::<LogFile>();
+		app.init_resource::<Tasks>();
 
 
 		app.add_event::<Execute>();
 		app.add_event::<Feedback>();
+		app.add_event::<Cancel>();
 
 		app.add_event::<Refresh>();
+		app.add_event::<Recorded>();
+		app.add_event::<CvarChanged>();
+
 
+		app.add_systems(
+			Startup, autoexec,
+		);
+
+		app.add_systems(
+			First,
+			frame.after(TimeSystem),
+		);
 
 		app.add_systems(
 			Update,
 			(
-				execute.run_if(common_conditions::on_event::<Execute>()),
-				feedback.run_if(common_conditions::on_event::<Feedback>()),
+				execute.run_if(common_conditions::on_event::<Execute>().or_else(pending)),
+				cancel.run_if(common_conditions::on_event::<Cancel>()),
+				task.after(cancel),
+				feedback
+					.after(task)
+					.run_if(common_conditions::on_event::<Feedback>()),
+				cvar_changed.after(execute),
+				recorded
+					.after(execute)
+					.after(feedback),
+				logfile
+					.after(recorded)
+					.run_if(common_conditions::on_event::<Recorded>()),
 			),
 		);
 	}
@@ -101,103 +427,1255 @@ pub(crate) fn execute(
 	mut app_exit: EventWriter<AppExit>,
 ) {
 	for v in execute.read() {
-		let input = v.trim();
+		let mut input = v.trim().to_string();
+
+		actuator.set_record_source(SOURCE);
+
+		if input.is_empty() {
+			actuator.push_record(
+				&input,
+				Level::Info,
+			);
+
+			continue;
+		}
+
+		if let Some(value) = input
+			.strip_prefix('!')
+			.and_then(|v| v.parse().ok())
+			.and_then(|v| actuator.get_history(v))
+			.cloned()
+		{
+			input = value;
+		}
+
+		if !input.starts_with('!') {
+			actuator.push_history(&input);
+		}
+
+		actuator.enqueue(
+			&input, 0,
+		);
+	}
+
+	actuator.tick();
+
+	while let Some((input, depth)) = actuator.dequeue() {
+		actuator.release_pipe();
+
+		actuator.set_record_source(SOURCE);
 
 		actuator.push_record(
-			input,
-			Level::Info,
+			&input,
+			if depth > 0 {
+				Level::Display
+			}
+			else {
+				Level::Info
+			},
 		);
 
-		if input.eq("") {
+		if let Some(number) = input
+			.strip_prefix('!')
+			.filter(|_| depth == 0)
+		{
+			actuator.push_record(
+				&tr!(
+					"history entry `{number}` does not exist",
+					number = number
+				),
+				Level::Error,
+			);
+
 			continue;
 		}
 
+		let mut filters = script::split_pipe(&input);
 
-		actuator.push_history(input);
+		if filters.is_empty() {
+			continue;
+		}
 
-		if let Some((bin, args)) = actuator.matcher(input) {
-			if bin.eq("exit") {
-				app_exit.send(AppExit::Success);
+		let input = filters.remove(0);
+
+		if let Some(value) = actuator.expand_alias(&input) {
+			if depth >= MAX_DEPTH {
+				actuator.push_record(
+					&tr!("command is nested too deeply"),
+					Level::Error,
+				);
 
-				return
+				continue;
 			}
 
-			if bin.eq("clear") {
-				actuator.clear();
+			let mut value = script::split(&value);
 
-				continue;
+			if let Some(last) = value
+				.last_mut()
+				.filter(|_| !filters.is_empty())
+			{
+				last.push_str(
+					&format!(
+						" | {}",
+						filters.join(" | ")
+					),
+				);
 			}
 
-			if bin.eq("help") {
-				let help = actuator.help();
+			actuator.prepend(
+				value,
+				depth + 1,
+			);
 
-				actuator.push_empty_record(1);
+			continue;
+		}
+
+		let filters = match filters
+			.iter()
+			.map(|v| Filter::parse(v))
+			.collect::<Result<Vec<_>, _>>()
+		{
+			| Ok(filters) => filters,
+			| Err(error) => {
 				actuator.push_record(
-					&help[..],
-					Level::Success,
+					&error,
+					Level::Error,
 				);
-				actuator.push_empty_record(1);
 
 				continue;
-			}
+			},
+		};
+
+		let (bin, args) = match actuator.matcher(&input) {
+			| Ok(v) => v,
+			| Err(error) => {
+				actuator.push_record(
+					&describe(&error),
+					Level::Error,
+				);
 
-			match args.subcommand() {
-				| Some(("help", _)) => {
-					let help = actuator.subcommand_help(&bin);
+				continue;
+			},
+		};
 
-					actuator.push_empty_record(1);
-					actuator.push_record(
-						&help[..],
-						Level::Success,
-					);
-					actuator.push_empty_record(1);
+		actuator.set_record_source(&bin);
 
-					continue;
-				},
+		if bin.eq("exec") && !filters.is_empty() {
+			actuator.push_record(
+				&tr!("the output of exec cannot be piped"),
+				Level::Error,
+			);
+
+			continue;
+		}
 
-				| _ => {},
+		if !filters.is_empty() {
+			actuator.begin_pipe(
+				filters, &bin,
+			);
+		}
+
+		if bin.eq("exit") {
+			app_exit.send(AppExit::Success);
+
+			return
+		}
+
+		if bin.eq("exec") {
+			let path = args
+				.get_one::<String>("path")
+				.cloned()
+				.unwrap_or_default();
+
+			if depth >= MAX_DEPTH {
+				actuator.push_record(
+					&tr!("command is nested too deeply"),
+					Level::Error,
+				);
+
+				continue;
 			}
 
-			if let Ok(id) = actuator.push(
-				&bin, args,
+			if let Err(error) = actuator.exec(
+				&path,
+				depth + 1,
 			) {
-				commands.run_system(*id);
+				actuator.push_record(
+					&tr!(
+						"cannot exec {path}: {error}",
+						path = path,
+						error = error
+					),
+					Level::Error,
+				);
 			}
+
+			continue;
+		}
+
+		if bin.eq("wait") {
+			let delay = args
+				.get_one::<Delay>("delay")
+				.copied()
+				.unwrap_or_default();
+
+			actuator.defer(delay);
+
+			continue;
 		}
-		else {
+
+		if bin.eq("after") || bin.eq("every") {
+			let delay = args
+				.get_one::<Delay>("delay")
+				.copied()
+				.unwrap_or_default();
+			let command = args
+				.get_one::<String>("command")
+				.cloned()
+				.unwrap_or_default();
+
+			let id = actuator.schedule(
+				delay,
+				&command,
+				bin.eq("every"),
+			);
+
 			actuator.push_record(
-				"command does not exist",
-				Level::Error,
+				&tr!(
+					"timer {id}: {bin} {delay}",
+					id = id,
+					bin = bin,
+					delay = delay
+				),
+				Level::Success,
 			);
-		};
-	}
 
-	refresh.send(Refresh);
-}
+			continue;
+		}
 
+		if bin.eq("cancel") {
+			let id = args
+				.get_one::<usize>("id")
+				.copied()
+				.unwrap_or_default();
 
-pub(crate) fn feedback(
-	mut actuator: ResMut<Actuator>,
-	mut feedback: EventReader<Feedback>,
-	mut refresh: EventWriter<Refresh>,
-) {
-	for v in feedback.read() {
-		match v {
-			| Feedback::Append(message, level) => {
+			if actuator.cancel_timer(id) {
 				actuator.push_record(
-					&message,
-					level.clone(),
+					&tr!(
+						"timer {id} cancelled",
+						id = id
+					),
+					Level::Success,
 				);
-			},
-			| Feedback::Replace(message, level) => {
-				actuator.replace_last_record(
-					&message,
-					level.clone(),
+			}
+			else {
+				actuator.push_record(
+					&tr!(
+						"timer `{id}` does not exist",
+						id = id
+					),
+					Level::Error,
 				);
-			},
+			}
+
+			continue;
 		}
 
+		if bin.eq("clear") {
+			actuator.clear();
 
-		refresh.send(Refresh);
+			continue;
+		}
+
+		if bin.eq("help") {
+			let help = actuator.help();
+
+			actuator.push_empty_record(1);
+			actuator.push_record(
+				&help[..],
+				Level::Success,
+			);
+			actuator.push_empty_record(1);
+
+			continue;
+		}
+
+		if bin.eq("history") {
+			let list = actuator.history_list(
+				args.get_one::<usize>("count")
+					.copied(),
+			);
+
+			actuator.push_empty_record(1);
+			actuator.push_record(
+				&list[..],
+				Level::Success,
+			);
+			actuator.push_empty_record(1);
+
+			continue;
+		}
+
+		if bin.eq("filter") {
+			let levels = args
+				.get_many::<String>("level")
+				.map(
+					|v| {
+						v.cloned()
+							.collect::<Vec<_>>()
+					},
+				)
+				.unwrap_or_default();
+
+			if levels
+				.iter()
+				.any(|v| v.eq("off"))
+			{
+				actuator.set_record_filter(None);
+			}
+			else if !levels.is_empty() {
+				actuator.set_record_filter(
+					Some(
+						levels
+							.iter()
+							.filter_map(|v| Level::parse(v))
+							.collect(),
+					),
+				);
+			}
+
+			let message = if let Some(filter) = actuator.get_record_filter() {
+				let mut levels = filter
+					.iter()
+					.map(|v| format!("{v:?}").to_lowercase())
+					.collect::<Vec<_>>();
+
+				levels.sort();
+
+				tr!(
+					"filter {levels}",
+					levels = levels.join(" ")
+				)
+			}
+			else {
+				tr!("filter off")
+			};
+
+			actuator.push_record(
+				&message,
+				Level::Success,
+			);
+
+			continue;
+		}
+
+		if bin.eq("condump") {
+			let path = args
+				.get_one::<String>("path")
+				.cloned()
+				.unwrap_or_default();
+
+			match actuator.dump_record(&path) {
+				| Ok(length) => {
+					actuator.push_record(
+						&tr!(
+							"dumped {length} lines to {path}",
+							length = length,
+							path = path
+						),
+						Level::Success,
+					);
+				},
+				| Err(error) => {
+					actuator.push_record(
+						&tr!(
+							"cannot dump to {path}: {error}",
+							path = path,
+							error = error
+						),
+						Level::Error,
+					);
+				},
+			}
+
+			continue;
+		}
+
+		if bin.eq("alias") {
+			let name = args
+				.get_one::<String>("name")
+				.cloned()
+				.unwrap_or_default();
+			let value = args
+				.get_one::<String>("command")
+				.cloned()
+				.unwrap_or_default();
+
+			if actuator.is_command(&name) {
+				actuator.push_record(
+					&tr!(
+						"`{name}` is already a command",
+						name = name
+					),
+					Level::Error,
+				);
+
+				continue;
+			}
+
+			if let Err(error) = actuator.insert_alias(
+				&name, &value,
+			) {
+				actuator.push_record(
+					&tr!(
+						"cannot save aliases: {error}",
+						error = error
+					),
+					Level::Warn,
+				);
+			}
+
+			continue;
+		}
+
+		if bin.eq("unalias") {
+			let name = args
+				.get_one::<String>("name")
+				.cloned()
+				.unwrap_or_default();
+
+			match actuator.remove_alias(&name) {
+				| Ok(Some(_)) => {},
+				| Ok(None) => {
+					actuator.push_record(
+						&tr!(
+							"alias `{name}` does not exist",
+							name = name
+						),
+						Level::Error,
+					);
+				},
+				| Err(error) => {
+					actuator.push_record(
+						&tr!(
+							"cannot save aliases: {error}",
+							error = error
+						),
+						Level::Warn,
+					);
+				},
+			}
+
+			continue;
+		}
+
+		if bin.eq("aliaslist") {
+			let list = actuator.alias_list();
+
+			actuator.push_empty_record(1);
+			actuator.push_record(
+				&list[..],
+				Level::Success,
+			);
+			actuator.push_empty_record(1);
+
+			continue;
+		}
+
+		if bin.eq("cvarlist") {
+			let list = actuator.cvar_list();
+
+			actuator.push_empty_record(1);
+			actuator.push_record(
+				&list[..],
+				Level::Success,
+			);
+			actuator.push_empty_record(1);
+
+			continue;
+		}
+
+		if bin.eq("language") {
+			if let Some(code) = args.get_one::<String>("code") {
+				if actuator
+					.set_language(code)
+					.is_none()
+				{
+					actuator.push_record(
+						&tr!(
+							"language `{code}` is not available, expected one of: {list}",
+							code = code,
+							list = locale::available().join(", ")
+						),
+						Level::Error,
+					);
+
+					continue;
+				}
+			}
+
+			actuator.push_record(
+				&tr!(
+					"language {code}",
+					code = locale::current()
+				),
+				Level::Success,
+			);
+
+			continue;
+		}
+
+		let command = actuator
+			.cvar(&bin)
+			.is_some_and(|v| v.is_command());
+
+		if command
+			|| matches!(
+				bin.as_str(),
+				"set" | "get" | "reset"
+			) {
+			let name = if command {
+				bin.clone()
+			}
+			else {
+				args.get_one::<String>("name")
+					.cloned()
+					.unwrap_or_default()
+			};
+
+			let value = args
+				.try_get_one::<String>("value")
+				.ok()
+				.flatten()
+				.cloned();
+
+			let result = match (
+				bin.as_str(),
+				value,
+			) {
+				| ("reset", _) => {
+					actuator
+						.reset_cvar(&name)
+						.cloned()
+				},
+				| (_, Some(value)) => {
+					actuator
+						.set_cvar(
+							&name, &value,
+						)
+						.cloned()
+				},
+				| _ => {
+					actuator
+						.cvar(&name)
+						.map(|v| v.get().clone())
+						.ok_or_else(|| cvar::Error::Missing(name.clone()))
+				},
+			};
+
+			match result {
+				| Ok(value) => {
+					let message = if command {
+						format!("{name} {value}")
+					}
+					else {
+						format!("{name} = {value}")
+					};
+
+					actuator.push_record(
+						&message,
+						Level::Success,
+					);
+				},
+				| Err(error) => {
+					actuator.push_record(
+						&error.to_string(),
+						Level::Error,
+					);
+				},
+			}
+
+			continue;
+		}
+
+		if let Err(permission) = actuator.check_permission(&bin) {
+			actuator.push_record(
+				&tr!(
+					"command `{bin}` {permission}",
+					bin = bin,
+					permission = permission
+				),
+				Level::Warn,
+			);
+
+			continue;
+		}
+
+		match args.subcommand() {
+			| Some(("help", _)) => {
+				let help = actuator.subcommand_help(&bin);
+
+				actuator.push_empty_record(1);
+				actuator.push_record(
+					&help[..],
+					Level::Success,
+				);
+				actuator.push_empty_record(1);
+
+				continue;
+			},
+
+			| _ => {},
+		}
+
+		if let Ok(id) = actuator.push(
+			&bin, args,
+		) {
+			commands.run_system(*id);
+
+			actuator.hold_pipe(&bin);
+		}
+	}
+
+	actuator.release_pipe();
+
+	refresh.send(Refresh);
+}
+
+
+fn describe(error: &clap::Error) -> String {
+	let kind = match error.kind() {
+		| ErrorKind::InvalidSubcommand => "unknown command",
+		| ErrorKind::MissingRequiredArgument | ErrorKind::MissingSubcommand => "missing argument",
+
+		| _ => "bad argument",
+	};
+
+	let message = error
+		.render()
+		.to_string();
+
+	format!(
+		"{}: {}",
+		tr!(kind),
+		message
+			.trim()
+			.trim_start_matches("error: ")
+	)
+}
+
+pub(crate) fn autoexec(mut actuator: ResMut<Actuator>) {
+	if Path::new(AUTOEXEC).is_file() {
+		actuator.enqueue(
+			&format!("exec {AUTOEXEC}"),
+			0,
+		);
+	}
+}
+
+pub(crate) fn pending(actuator: Res<Actuator>) -> bool {
+	actuator.is_pending()
+}
+
+
+pub(crate) fn feedback(
+	mut actuator: ResMut<Actuator>,
+	tasks: Res<Tasks>,
+	mut feedback: EventReader<Feedback>,
+	mut refresh: EventWriter<Refresh>,
+) {
+	for v in feedback.read() {
+		match v {
+			| Feedback::Append(source, message, level) => {
+				actuator.set_record_source(source);
+				actuator.push_record(
+					message,
+					level.clone(),
+				);
+			},
+			| Feedback::Replace(key, source, message, level) => {
+				actuator.set_record_source(source);
+				actuator.update_record(
+					*key,
+					message,
+					level.clone(),
+				);
+
+				if !tasks.contains(*key) {
+					actuator.forget_record(*key);
+
+					if !tasks.is_running(source) {
+						actuator.answer_pipe(source);
+					}
+				}
+			},
+			| Feedback::Done(source) => {
+				if !tasks.is_running(source) {
+					actuator.answer_pipe(source);
+				}
+			},
+		}
+
+
+		refresh.send(Refresh);
+	}
+
+	actuator.set_record_source(SOURCE);
+}
+
+
+pub(crate) fn task(
+	mut actuator: ResMut<Actuator>,
+	mut tasks: ResMut<Tasks>,
+	mut feedback: EventWriter<Feedback>,
+) {
+	feedback.send_batch(tasks.poll());
+
+	for bin in tasks.running() {
+		actuator.hold_pipe(&bin);
+	}
+}
+
+pub(crate) fn cancel(
+	mut actuator: ResMut<Actuator>,
+	mut tasks: ResMut<Tasks>,
+	mut cancel: EventReader<Cancel>,
+	mut refresh: EventWriter<Refresh>,
+) {
+	cancel.clear();
+
+	if tasks.cancel() == 0 {
+		actuator.set_record_source(SOURCE);
+		actuator.push_record(
+			&tr!("no task is running"),
+			Level::Warn,
+		);
+
+		refresh.send(Refresh);
+	}
+}
+
+
+pub(crate) fn cvar_changed(
+	mut actuator: ResMut<Actuator>,
+	mut cvar_changed: EventWriter<CvarChanged>,
+) {
+	for (name, value) in actuator.changed_cvar() {
+		cvar_changed.send(CvarChanged { name, value });
+	}
+}
+
+pub(crate) fn recorded(mut actuator: ResMut<Actuator>, mut recorded: EventWriter<Recorded>) {
+	for v in actuator.recorded() {
+		recorded.send(Recorded(v));
+	}
+}
+
+pub(crate) fn frame(
+	mut actuator: ResMut<Actuator>,
+	frame_count: Res<FrameCount>,
+	time: Res<Time>,
+	mut refresh: EventWriter<Refresh>,
+) {
+	actuator.set_record_frame(frame_count.0);
+	actuator.set_timer_clock(
+		frame_count.0,
+		time.elapsed(),
+	);
+
+	if actuator.advance_pipe() {
+		refresh.send(Refresh);
+	}
+}
+
+pub(crate) fn logfile(mut logfile: ResMut<LogFile>, mut recorded: EventReader<Recorded>) {
+	for Recorded(entry) in recorded.read() {
+		logfile.write(entry);
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use std::{
+		sync::{
+			atomic::{AtomicBool, Ordering},
+			Arc,
+		},
+		thread,
+		time::Duration,
+	};
+
+	use bevy::{
+		app::{App, Update},
+		ecs::event::{Event, EventReader, EventWriter, Events},
+		prelude::{AppExtStates, NextState, States},
+		state::app::StatesPlugin,
+		MinimalPlugins,
+	};
+
+	use super::{
+		event::{Cancel, Execute, Feedback},
+		user, Actuator, AddCommandEvent, AddCommandTask, AddCvar, Cvar, Level, Permission, Plugin,
+		RemoveCommand, ScopeCommand, SetPermission, Value,
+	};
+
+
+	#[derive(Event, PartialEq, Debug)]
+	struct Hit(u8);
+
+	#[derive(Event)]
+	struct Say;
+
+	#[derive(States, Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+	enum Mode {
+		#[default]
+		Menu,
+		Game,
+	}
+
+
+	fn app(name: &str) -> App {
+		user::scratch(name);
+
+		let mut app = App::new();
+
+		app.add_plugins(
+			(
+				MinimalPlugins, StatesPlugin, Plugin,
+			),
+		);
+
+		app.add_command_event(
+			clap::Command::new("hit"),
+			|_| Some(Hit(1)),
+		);
+
+		app
+	}
+
+	fn run(app: &mut App, input: &str) -> Vec<Hit> {
+		app.world_mut()
+			.send_event(Execute::new(input.to_string()));
+
+		app.update();
+		app.update();
+
+		app.world_mut()
+			.resource_mut::<Events<Hit>>()
+			.drain()
+			.collect()
+	}
+
+	fn task(app: &mut App) -> Arc<AtomicBool> {
+		let gate = Arc::new(AtomicBool::new(false));
+
+		let value = gate.clone();
+
+		app.add_command_task(
+			clap::Command::new("work"),
+			move |_, progress| {
+				let gate = value.clone();
+
+				async move {
+					progress.report("half");
+
+					while !gate.load(Ordering::Relaxed) {
+						if progress.is_cancelled() {
+							return Err(String::from("stopped"));
+						}
+
+						thread::sleep(Duration::from_millis(1));
+					}
+
+					Ok(String::from("done"))
+				}
+			},
+		);
+
+		gate
+	}
+
+	fn messages(app: &App) -> Vec<String> {
+		app.world()
+			.resource::<Actuator>()
+			.visible_record()
+			.into_iter()
+			.map(|v| v.message.clone())
+			.collect()
+	}
+
+	fn wait(app: &mut App, message: &str) {
+		for _ in 0..1000 {
+			app.update();
+
+			if messages(app)
+				.iter()
+				.any(|v| v.eq(message))
+			{
+				return;
+			}
+
+			thread::sleep(Duration::from_millis(1));
+		}
+
+		panic!("`{message}` was never recorded");
+	}
+
+
+	#[test]
+	fn replace_command() {
+		let mut app = app("replace_command");
+
+		app.set_permission(
+			"hit",
+			Permission::Cheat,
+		);
+
+		app.replace_command_event(
+			clap::Command::new("hit"),
+			|_| Some(Hit(2)),
+		);
+
+		assert_eq!(
+			app.world()
+				.resource::<Actuator>()
+				.check_permission("hit"),
+			Err(Permission::Cheat)
+		);
+
+		app.set_permission(
+			"hit",
+			Permission::Normal,
+		);
+
+		assert_eq!(
+			run(&mut app, "hit"),
+			vec![Hit(2)]
+		);
+	}
+
+	#[test]
+	fn permission_missing_command() {
+		let mut app = app("permission_missing_command");
+
+		app.set_permission(
+			"nope",
+			Permission::Cheat,
+		);
+
+		assert_eq!(
+			messages(&app),
+			vec!["cannot set the permission of `nope`, the command does not exist"]
+		);
+	}
+
+	#[test]
+	fn remove_command() {
+		let mut app = app("remove_command");
+
+		app.remove_command("hit");
+
+		assert!(
+			!app.world()
+				.resource::<Actuator>()
+				.is_command("hit")
+		);
+
+		assert!(run(&mut app, "hit").is_empty());
+
+		app.add_command_event(
+			clap::Command::new("hit"),
+			|_| Some(Hit(3)),
+		);
+
+		assert_eq!(
+			run(&mut app, "hit"),
+			vec![Hit(3)]
+		);
+	}
+
+	#[test]
+	fn scope_command() {
+		let mut app = app("scope_command");
+
+		app.init_state::<Mode>();
+		app.scope_command(
+			"hit",
+			Mode::Game,
+		);
+
+		app.update();
+
+		assert!(run(&mut app, "hit").is_empty());
+
+		app.world_mut()
+			.resource_mut::<NextState<Mode>>()
+			.set(Mode::Game);
+
+		app.update();
+
+		assert_eq!(
+			run(&mut app, "hit"),
+			vec![Hit(1)]
+		);
+
+		app.world_mut()
+			.resource_mut::<NextState<Mode>>()
+			.set(Mode::Menu);
+
+		app.update();
+
+		assert!(run(&mut app, "hit").is_empty());
+	}
+
+	#[test]
+	fn wait_own_sequence() {
+		let mut app = app("wait_own_sequence");
+
+		app.world_mut()
+			.send_event(Execute::new(String::from("wait 3f; hit")));
+
+		assert_eq!(
+			run(&mut app, "hit"),
+			vec![Hit(1)]
+		);
+
+		for _ in 0..3 {
+			app.update();
+		}
+
+		assert_eq!(
+			app.world_mut()
+				.resource_mut::<Events<Hit>>()
+				.drain()
+				.collect::<Vec<_>>(),
+			vec![Hit(1)]
+		);
+	}
+
+	#[test]
+	fn pipe_own_sequence() {
+		let mut app = app("pipe_own_sequence");
+
+		app.add_command_event(
+			clap::Command::new("say"),
+			|_| Some(Say),
+		);
+		app.add_systems(
+			Update,
+			|mut say: EventReader<Say>, mut feedback: EventWriter<Feedback>| {
+				for _ in say.read() {
+					for message in ["a", "b"] {
+						feedback.send(
+							Feedback::Append(
+								String::from("say"),
+								String::from(message),
+								Level::Info,
+							),
+						);
+					}
+
+					feedback.send(Feedback::Done(String::from("say")));
+				}
+			},
+		);
+
+		app.world_mut()
+			.send_event(Execute::new(String::from("say | count; hit")));
+
+		assert_eq!(
+			run(&mut app, "hit"),
+			vec![Hit(1)]
+		);
+
+		wait(
+			&mut app, "2",
+		);
+
+		assert_eq!(
+			messages(&app),
+			vec!["say | count", "hit", "2", "hit"]
+		);
+	}
+
+	#[test]
+	fn pipe_exec() {
+		let mut app = app("pipe_exec");
+
+		run(
+			&mut app,
+			"exec autoexec.cfg | grep x",
+		);
+
+		assert_eq!(
+			messages(&app),
+			vec![
+				"exec autoexec.cfg | grep x",
+				"the output of exec cannot be piped"
+			]
+		);
+	}
+
+	#[test]
+	fn expand_when_run() {
+		let mut app = app("expand_when_run");
+
+		app.add_cvar(
+			Cvar::new(
+				"who",
+				Value::Text(String::from("a")),
+			),
+		);
+		app.add_cvar(
+			Cvar::new(
+				"last",
+				Value::Text(String::new()),
+			),
+		);
+
+		let mut actuator = app
+			.world_mut()
+			.resource_mut::<Actuator>();
+
+		let (_, args) = actuator
+			.matcher("every ${frame}f \"get $who\"")
+			.unwrap();
+
+		assert_eq!(
+			args.get_one::<String>("command"),
+			Some(&String::from("get $who"))
+		);
+
+		let (_, args) = actuator
+			.matcher("get $who")
+			.unwrap();
+
+		assert_eq!(
+			args.get_one::<String>("name"),
+			Some(&String::from("a"))
+		);
+
+		for input in ["alias greet \"set last $who\"", "set who b", "greet"] {
+			app.world_mut()
+				.send_event(Execute::new(input.to_string()));
+		}
+
+		app.update();
+
+		assert_eq!(
+			messages(&app).last(),
+			Some(&String::from("last = \"b\""))
+		);
+	}
+
+	#[test]
+	fn task_progress() {
+		let mut app = app("task_progress");
+
+		let gate = task(&mut app);
+
+		app.world_mut()
+			.send_event(Execute::new(String::from("work")));
+
+		wait(
+			&mut app, "work: half",
+		);
+
+		app.world_mut()
+			.send_event(
+				Feedback::Append(
+					String::from("other"),
+					String::from("noise"),
+					Level::Info,
+				),
+			);
+
+		gate.store(
+			true,
+			Ordering::Relaxed,
+		);
+
+		wait(
+			&mut app, "work: done",
+		);
+
+		assert_eq!(
+			messages(&app),
+			vec!["work", "work: done", "noise"]
+		);
+	}
+
+	#[test]
+	fn task_cancel() {
+		let mut app = app("task_cancel");
+
+		task(&mut app);
+
+		app.world_mut()
+			.send_event(Execute::new(String::from("work")));
+
+		wait(
+			&mut app, "work: half",
+		);
+
+		app.world_mut()
+			.send_event(Cancel);
+
+		wait(
+			&mut app, "work: cancelled",
+		);
+
+		assert_eq!(
+			messages(&app),
+			vec!["work", "work: cancelled"]
+		);
+	}
+
+	#[test]
+	fn cvar_command() {
+		let mut app = app("cvar_command");
+
+		app.add_cvar(
+			Cvar::new(
+				"speed",
+				Value::Integer(1),
+			)
+			.range(
+				0.0, 5.0,
+			)
+			.command(),
+		);
+		app.add_cvar(
+			Cvar::new(
+				"overlay",
+				Value::Bool(false),
+			)
+			.command(),
+		);
+
+		for input in ["speed 3.0", "speed 9", "speed", "overlay on", "get speed"] {
+			app.world_mut()
+				.send_event(Execute::new(input.to_string()));
+		}
+
+		app.update();
+
+		assert_eq!(
+			messages(&app),
+			vec![
+				"speed 3.0",
+				"speed 3",
+				"speed 9",
+				"variable `speed` must be between 0 and 5",
+				"speed",
+				"speed 3",
+				"overlay on",
+				"overlay 1",
+				"get speed",
+				"speed = 3"
+			]
+		);
 	}
 }
@@ -0,0 +1,62 @@
+use std::fmt;
+
+use super::locale::tr;
+
+pub const CHEATS: &str = "cheats";
+
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub enum Permission {
+	#[default]
+	Normal,
+	Cheat,
+	Developer,
+}
+
+impl Permission {
+	pub fn allows(&self, cheats: bool) -> bool {
+		match self {
+			| Permission::Normal => true,
+			| Permission::Cheat => cheats,
+			| Permission::Developer => {
+				cfg!(
+					any(
+						debug_assertions,
+						feature = "developer"
+					)
+				)
+			},
+		}
+	}
+}
+
+impl fmt::Display for Permission {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			| Permission::Normal => {
+				write!(
+					f,
+					"{}",
+					tr!("normal")
+				)
+			},
+			| Permission::Cheat => {
+				write!(
+					f,
+					"{}",
+					tr!(
+						"requires cheats, enable them with `set {name} 1`",
+						name = CHEATS
+					)
+				)
+			},
+			| Permission::Developer => {
+				write!(
+					f,
+					"{}",
+					tr!("is only available in developer builds")
+				)
+			},
+		}
+	}
+}
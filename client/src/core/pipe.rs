@@ -0,0 +1,328 @@
+use regex::Regex;
+
+use super::{locale::tr, Level};
+
+pub const TIMEOUT_FRAMES: u32 = 60;
+
+
+#[derive(Debug)]
+pub enum Filter {
+	Grep(Regex),
+	Head(usize),
+	Tail(usize),
+	Count,
+}
+
+impl Filter {
+	pub fn parse(input: &str) -> Result<Self, String> {
+		let args = shlex::split(input).ok_or_else(
+			|| {
+				tr!(
+					"bad filter: {reason}",
+					reason = input
+				)
+			},
+		)?;
+
+		let matches = matcher()
+			.try_get_matches_from(args)
+			.map_err(
+				|v| {
+					tr!(
+						"bad filter: {reason}",
+						reason = v
+							.render()
+							.to_string()
+							.trim()
+							.trim_start_matches("error: ")
+					)
+				},
+			)?;
+
+		let count = |arg: &clap::ArgMatches| {
+			arg.get_one::<usize>("count")
+				.copied()
+				.unwrap_or(10)
+		};
+
+		match matches.subcommand() {
+			| Some(("grep", arg)) => {
+				let pattern = arg
+					.get_one::<String>("pattern")
+					.cloned()
+					.unwrap_or_default();
+
+				Regex::new(&pattern)
+					.map(Filter::Grep)
+					.map_err(
+						|v| {
+							tr!(
+								"bad filter: {reason}",
+								reason = v
+							)
+						},
+					)
+			},
+			| Some(("head", arg)) => Ok(Filter::Head(count(arg))),
+			| Some(("tail", arg)) => Ok(Filter::Tail(count(arg))),
+			| Some(("count", _)) => Ok(Filter::Count),
+
+			| _ => {
+				Err(
+					tr!(
+						"bad filter: {reason}",
+						reason = input
+					),
+				)
+			},
+		}
+	}
+}
+
+
+#[derive(Debug)]
+pub struct Pipe {
+	filters:  Vec<Filter>,
+	source:   String,
+	sequence: usize,
+	hold:     u32,
+}
+
+impl Pipe {
+	pub fn new(filters: Vec<Filter>, source: &str, sequence: usize) -> Self {
+		Self {
+			filters,
+			source: source.to_string(),
+			sequence,
+			hold: 0,
+		}
+	}
+
+	pub fn source(&self) -> &str {
+		&self.source
+	}
+
+	pub fn sequence(&self) -> usize {
+		self.sequence
+	}
+
+	pub fn is_held(&self) -> bool {
+		self.hold > 0
+	}
+
+	pub fn hold(&mut self) {
+		self.hold = TIMEOUT_FRAMES;
+	}
+
+	pub fn advance(&mut self) -> bool {
+		self.hold = self
+			.hold
+			.saturating_sub(1);
+
+		self.hold == 0
+	}
+
+	pub fn apply(
+		&self,
+		lines: Vec<(
+			String,
+			Level,
+		)>,
+	) -> Vec<(
+		String,
+		Level,
+	)> {
+		self.filters
+			.iter()
+			.fold(
+				lines
+					.into_iter()
+					.filter(|(v, _)| !v.is_empty())
+					.collect::<Vec<_>>(),
+				|mut lines, filter| {
+					match filter {
+						| Filter::Grep(regex) => {
+							lines.retain(|(v, _)| regex.is_match(v));
+						},
+						| Filter::Head(count) => {
+							lines.truncate(*count);
+						},
+						| Filter::Tail(count) => {
+							lines.drain(
+								..lines
+									.len()
+									.saturating_sub(*count),
+							);
+						},
+						| Filter::Count => {
+							lines = vec![(
+								lines.len().to_string(),
+								Level::Success,
+							)];
+						},
+					}
+
+					lines
+				},
+			)
+	}
+}
+
+
+fn matcher() -> clap::Command {
+	clap::Command::new("")
+		.no_binary_name(true)
+		.disable_help_flag(true)
+		.disable_help_subcommand(true)
+		.subcommand_required(true)
+		.subcommand(
+			clap::Command::new("grep")
+				.about("keep the lines matching a regex")
+				.arg(clap::arg!(<pattern> "regex")),
+		)
+		.subcommand(
+			clap::Command::new("head")
+				.about("keep the first lines")
+				.arg(
+					clap::arg!([count] "number of lines").value_parser(clap::value_parser!(usize)),
+				),
+		)
+		.subcommand(
+			clap::Command::new("tail")
+				.about("keep the last lines")
+				.arg(
+					clap::arg!([count] "number of lines").value_parser(clap::value_parser!(usize)),
+				),
+		)
+		.subcommand(clap::Command::new("count").about("count the lines"))
+}
+
+
+
+
+#[cfg(test)]
+mod test {
+	use super::{Filter, Pipe};
+	use crate::core::Level;
+
+
+	fn apply(filters: &[&str], lines: &[&str]) -> Vec<String> {
+		let filters = filters
+			.iter()
+			.map(|v| Filter::parse(v).unwrap())
+			.collect();
+
+		Pipe::new(
+			filters, "test", 0,
+		)
+		.apply(
+			lines
+				.iter()
+				.map(
+					|v| {
+						(
+							v.to_string(),
+							Level::Info,
+						)
+					},
+				)
+				.collect(),
+		)
+		.into_iter()
+		.map(|(v, _)| v)
+		.collect()
+	}
+
+
+	#[test]
+	fn parse_filter() {
+		assert!(
+			matches!(
+				Filter::parse("head"),
+				Ok(Filter::Head(10))
+			)
+		);
+
+		assert!(
+			matches!(
+				Filter::parse("tail 3"),
+				Ok(Filter::Tail(3))
+			)
+		);
+
+		assert!(
+			matches!(
+				Filter::parse("count"),
+				Ok(Filter::Count)
+			)
+		);
+
+		for input in ["", "sort", "head x", "grep", "grep ("] {
+			assert!(
+				Filter::parse(input).is_err(),
+				"{input}"
+			);
+		}
+	}
+
+	#[test]
+	fn apply_filter() {
+		let lines = ["alpha", "", "beta", "gamma", "delta"];
+
+		assert_eq!(
+			apply(
+				&["grep a$"],
+				&lines
+			),
+			vec!["alpha", "beta", "gamma", "delta"]
+		);
+
+		assert_eq!(
+			apply(
+				&["grep '^(b|g)'"],
+				&lines
+			),
+			vec!["beta", "gamma"]
+		);
+
+		assert_eq!(
+			apply(
+				&["head 2"],
+				&lines
+			),
+			vec!["alpha", "beta"]
+		);
+
+		assert_eq!(
+			apply(
+				&["tail 2"],
+				&lines
+			),
+			vec!["gamma", "delta"]
+		);
+
+		assert_eq!(
+			apply(
+				&["tail 9"],
+				&lines
+			),
+			vec!["alpha", "beta", "gamma", "delta"]
+		);
+
+		assert_eq!(
+			apply(
+				&["grep e", "head 2", "count"],
+				&lines
+			),
+			vec!["2"]
+		);
+
+		assert_eq!(
+			apply(
+				&["count"],
+				&[]
+			),
+			vec!["0"]
+		);
+	}
+}
@@ -1,7 +1,14 @@
-use std::collections::{vec_deque::Iter, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use bevy::prelude::Resource;
 
+use super::timestamp::Timestamp;
+
+
+
+pub const SOURCE: &str = "console";
+
+
 #[derive(Clone, Eq, PartialEq, Debug, Hash, Default)]
 pub enum Level {
 	#[default]
@@ -12,21 +19,51 @@ pub enum Level {
 	Display,
 }
 
+impl Level {
+	pub fn parse(value: &str) -> Option<Self> {
+		match value {
+			| "info" => Some(Level::Info),
+			| "success" => Some(Level::Success),
+			| "error" => Some(Level::Error),
+			| "warn" => Some(Level::Warn),
+			| "display" => Some(Level::Display),
+			| _ => None,
+		}
+	}
+}
+
+
+#[derive(Clone, Debug)]
+pub struct Entry {
+	pub id:        usize,
+	pub message:   String,
+	pub level:     Level,
+	pub source:    String,
+	pub frame:     u32,
+	pub timestamp: Timestamp,
+}
+
 
 #[derive(Resource, Default, Debug)]
 pub struct Record {
 	max: usize,
 
-	value: VecDeque<(
-		String,
-		Level,
-	)>,
+	source: String,
+	frame:  u32,
+	filter: Option<HashSet<Level>>,
+	count:  usize,
+
+	value:    VecDeque<Entry>,
+	recorded: Vec<Entry>,
+	lines:    HashMap<usize, usize>,
+	capture:  HashMap<String, Vec<Entry>>,
 }
 
 impl Record {
 	pub fn new(max: usize) -> Self {
 		Self {
 			max,
+			source: String::from(SOURCE),
 			..Default::default()
 		}
 	}
@@ -35,19 +72,60 @@ impl Record {
 		self.value.clear()
 	}
 
+	pub fn set_source(&mut self, source: &str) {
+		self.source = source.to_string();
+	}
+
+	pub fn source(&self) -> &str {
+		&self.source
+	}
+
+	pub fn set_frame(&mut self, frame: u32) {
+		self.frame = frame;
+	}
+
+	pub fn set_filter(&mut self, filter: Option<HashSet<Level>>) {
+		self.filter = filter;
+	}
+
+	pub fn get_filter(&self) -> Option<&HashSet<Level>> {
+		self.filter.as_ref()
+	}
+
+	pub fn begin_capture(&mut self, source: &str) {
+		self.capture.insert(
+			source.to_string(),
+			vec![],
+		);
+	}
+
+	pub fn end_capture(
+		&mut self,
+		source: &str,
+	) -> Vec<(
+		String,
+		Level,
+	)> {
+		self.capture
+			.remove(source)
+			.unwrap_or_default()
+			.into_iter()
+			.map(
+				|v| {
+					(
+						v.message, v.level,
+					)
+				},
+			)
+			.collect()
+	}
+
 	pub fn push(&mut self, message: &str, level: Level) {
-		for v in message.split("\n") {
-			self.value.push_back(
-				(
-					v.to_string(),
-					level.clone(),
-				),
-			);
+		let entries = self.entries(
+			message, level,
+		);
 
-			if self.value.len() > self.max {
-				self.value.pop_front();
-			}
-		}
+		self.append(entries);
 	}
 
 	pub fn push_empty(&mut self, quantity: usize) {
@@ -59,46 +137,235 @@ impl Record {
 		}
 	}
 
-	pub fn replace_last(&mut self, message: &str, level: Level) {
-		self.value.pop_back();
-
-		self.push(
+	pub fn update(&mut self, key: usize, message: &str, level: Level) {
+		let entries = self.entries(
 			message, level,
 		);
-	}
 
-	pub fn get(
-		&self,
-		index: &Option<usize>,
-		quantity: &usize,
-	) -> Iter<(
-		String,
-		Level,
-	)> {
-		let last_index = self.last_index();
+		let Some(id) = self.lines.insert(
+			key, entries[0].id,
+		)
+		else {
+			self.append(entries);
 
-		let end = last_index.min(if let Some(v) = index { *v } else { last_index });
+			return;
+		};
 
-		let start = end
-			.saturating_add(1)
-			.saturating_sub(*quantity);
+		if let Some(capture) = self
+			.capture
+			.get_mut(&self.source)
+		{
+			match capture
+				.iter()
+				.position(|v| v.id == id)
+			{
+				| Some(index) => {
+					capture.splice(
+						index..=index,
+						entries,
+					);
+				},
+				| None => capture.extend(entries),
+			}
 
-		if start == end {
-			self.value.iter()
+			return;
 		}
+
+		let Some(index) = self
+			.value
+			.iter()
+			.position(|v| v.id == id)
 		else {
-			self.value
-				.range(start..=end)
+			self.append(entries);
+
+			return;
+		};
+
+		self.value.remove(index);
+
+		for (i, entry) in entries
+			.iter()
+			.enumerate()
+		{
+			self.value.insert(
+				index + i,
+				entry.clone(),
+			);
 		}
+
+		self.recorded
+			.extend(entries);
+
+		self.truncate();
+	}
+
+	pub fn forget(&mut self, key: usize) {
+		self.lines.remove(&key);
 	}
 
-	pub fn len(&self) -> usize {
-		self.value.len()
+	pub fn recorded(&mut self) -> Vec<Entry> {
+		self.recorded
+			.drain(..)
+			.collect()
 	}
 
-	pub fn last_index(&self) -> usize {
+	pub fn iter(&self) -> impl Iterator<Item = &Entry> {
+		self.value.iter()
+	}
+
+	pub fn visible(&self) -> Vec<&Entry> {
 		self.value
-			.len()
-			.saturating_sub(1)
+			.iter()
+			.filter(
+				|v| {
+					self.filter
+						.as_ref()
+						.is_none_or(|filter| filter.contains(&v.level))
+				},
+			)
+			.collect()
+	}
+
+	fn entries(&mut self, message: &str, level: Level) -> Vec<Entry> {
+		let timestamp = Timestamp::now();
+
+		message
+			.split("\n")
+			.map(
+				|v| {
+					self.count += 1;
+
+					Entry {
+						id: self.count,
+						message: v.to_string(),
+						level: level.clone(),
+						source: self.source.clone(),
+						frame: self.frame,
+						timestamp,
+					}
+				},
+			)
+			.collect()
+	}
+
+	fn append(&mut self, entries: Vec<Entry>) {
+		if let Some(capture) = self
+			.capture
+			.get_mut(&self.source)
+		{
+			capture.extend(entries);
+
+			return;
+		}
+
+		for entry in entries {
+			self.recorded
+				.push(entry.clone());
+			self.value
+				.push_back(entry);
+		}
+
+		self.truncate();
+	}
+
+	fn truncate(&mut self) {
+		while self.value.len() > self.max {
+			self.value.pop_front();
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::{Level, Record};
+
+
+	fn messages(record: &Record) -> Vec<&str> {
+		record
+			.iter()
+			.map(|v| v.message.as_str())
+			.collect()
+	}
+
+
+	#[test]
+	fn capture_by_source() {
+		let mut record = Record::new(10);
+
+		record.begin_capture("res");
+
+		record.set_source("res");
+		record.update(
+			1,
+			"a",
+			Level::Info,
+		);
+		record.push(
+			"b",
+			Level::Display,
+		);
+		record.update(
+			1,
+			"c",
+			Level::Success,
+		);
+
+		record.set_source("bevy");
+		record.push(
+			"log",
+			Level::Info,
+		);
+
+		assert_eq!(
+			record.end_capture("res"),
+			vec![
+				(
+					String::from("c"),
+					Level::Success
+				),
+				(
+					String::from("b"),
+					Level::Display
+				),
+			]
+		);
+
+		assert_eq!(
+			messages(&record),
+			vec!["log"]
+		);
+	}
+
+	#[test]
+	fn update_own_line() {
+		let mut record = Record::new(10);
+
+		record.update(
+			1,
+			"work: 10%",
+			Level::Info,
+		);
+		record.push(
+			"other",
+			Level::Info,
+		);
+		record.update(
+			1,
+			"work: done\nsummary",
+			Level::Success,
+		);
+		record.forget(1);
+		record.update(
+			1,
+			"again",
+			Level::Info,
+		);
+
+		assert_eq!(
+			messages(&record),
+			vec!["work: done", "summary", "other", "again"]
+		);
 	}
 }
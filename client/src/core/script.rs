@@ -0,0 +1,228 @@
+use std::{fs, io, path::Path};
+
+pub fn split(input: &str) -> Vec<String> {
+	split_by(
+		input, ';',
+	)
+}
+
+pub fn split_pipe(input: &str) -> Vec<String> {
+	split_by(
+		input, '|',
+	)
+}
+
+fn split_by(input: &str, separator: char) -> Vec<String> {
+	let mut value = vec![];
+	let mut current = String::new();
+	let mut quote: Option<char> = None;
+	let mut escape = false;
+
+	for c in input.chars() {
+		if escape {
+			escape = false;
+		}
+		else if c == '\\' {
+			escape = true;
+		}
+		else if let Some(q) = quote {
+			if c == q {
+				quote = None;
+			}
+		}
+		else if c == '"' || c == '\'' {
+			quote = Some(c);
+		}
+		else if c == separator {
+			value.push(current.clone());
+			current.clear();
+
+			continue;
+		}
+
+		current.push(c);
+	}
+
+	value.push(current);
+
+	value
+		.into_iter()
+		.map(|v| v.trim().to_string())
+		.filter(|v| !v.is_empty())
+		.collect()
+}
+
+pub fn expand<F>(input: &str, lookup: F) -> String
+where F: Fn(&str) -> Option<String> {
+	let mut value = String::new();
+	let mut chars = input.chars().peekable();
+	let mut single = false;
+	let mut double = false;
+
+	while let Some(c) = chars.next() {
+		match c {
+			| '\\' if !single => {
+				value.push(c);
+				value.extend(chars.next());
+			},
+			| '\'' if !double => {
+				single = !single;
+				value.push(c);
+			},
+			| '"' if !single => {
+				double = !double;
+				value.push(c);
+			},
+			| '$' if !single => {
+				let (name, original) = match chars.peek() {
+					| Some('{') => {
+						chars.next();
+
+						let name = chars
+							.by_ref()
+							.take_while(|v| *v != '}')
+							.collect::<String>();
+
+						(
+							name.clone(),
+							format!("${{{name}}}"),
+						)
+					},
+					| Some(v) if *v == '*' || v.is_ascii_digit() => {
+						let name = chars
+							.next()
+							.map(String::from)
+							.unwrap_or_default();
+
+						(
+							name.clone(),
+							format!("${name}"),
+						)
+					},
+					| _ => {
+						let mut name = String::new();
+
+						while let Some(v) = chars.next_if(|v| v.is_alphanumeric() || *v == '_') {
+							name.push(v);
+						}
+
+						(
+							name.clone(),
+							format!("${name}"),
+						)
+					},
+				};
+
+				match lookup(&name).filter(|_| !name.is_empty()) {
+					| Some(v) => value.push_str(&v),
+					| None => value.push_str(&original),
+				}
+			},
+			| _ => value.push(c),
+		}
+	}
+
+	value
+}
+
+pub fn quote(value: &str) -> String {
+	shlex::try_quote(value)
+		.map(|v| v.to_string())
+		.unwrap_or_default()
+}
+
+pub fn join(value: &[String]) -> String {
+	value
+		.iter()
+		.map(|v| quote(v))
+		.collect::<Vec<_>>()
+		.join(" ")
+}
+
+pub fn read<P>(path: P) -> io::Result<Vec<String>>
+where P: AsRef<Path> {
+	let content = fs::read_to_string(path)?;
+
+	Ok(
+		content
+			.lines()
+			.map(str::trim)
+			.filter(|v| !v.is_empty())
+			.filter(|v| !v.starts_with('#') && !v.starts_with("//"))
+			.flat_map(split)
+			.collect(),
+	)
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::{expand, split, split_pipe};
+
+
+	fn lookup(name: &str) -> Option<String> {
+		match name {
+			| "name" => Some(String::from("slugma")),
+			| "1" => Some(String::from("one")),
+			| "*" => Some(String::from("one two")),
+			| _ => None,
+		}
+	}
+
+
+	#[test]
+	fn split_commands() {
+		assert_eq!(
+			split("set a 1; get a ;; help"),
+			vec!["set a 1", "get a", "help"]
+		);
+
+		assert_eq!(
+			split(r#"alias x "a; b"; bind f1 'c; d'"#),
+			vec![r#"alias x "a; b""#, "bind f1 'c; d'"]
+		);
+
+		assert_eq!(
+			split(r"echo a\; b; c"),
+			vec![r"echo a\; b", "c"]
+		);
+	}
+
+	#[test]
+	fn split_pipes() {
+		assert_eq!(
+			split_pipe("help | grep 'a|b' | head 3"),
+			vec!["help", "grep 'a|b'", "head 3"]
+		);
+
+		assert!(split_pipe(" | ").is_empty());
+	}
+
+	#[test]
+	fn expand_variables() {
+		assert_eq!(
+			expand(
+				"say $name ${name}s $1 $*",
+				lookup
+			),
+			"say slugma slugmas one one two"
+		);
+
+		assert_eq!(
+			expand(
+				r#"say "$name" '$name' \$name"#,
+				lookup
+			),
+			r#"say "slugma" '$name' \$name"#
+		);
+
+		assert_eq!(
+			expand(
+				"say $missing ${missing} $ $2",
+				lookup
+			),
+			"say $missing ${missing} $ $2"
+		);
+	}
+}
@@ -0,0 +1,200 @@
+use std::{collections::BTreeMap, fs, io, path::PathBuf};
+
+use super::script;
+
+#[derive(Default, Debug)]
+pub struct Table {
+	bin:   &'static str,
+	path:  PathBuf,
+	value: BTreeMap<String, String>,
+}
+
+impl Table {
+	pub fn new(bin: &'static str, path: PathBuf, key: fn(&str) -> String) -> Self {
+		let mut table = Self {
+			bin,
+			path,
+			..Default::default()
+		};
+
+		table.load(key);
+
+		table
+	}
+
+	pub fn get(&self, key: &str) -> Option<&String> {
+		self.value.get(key)
+	}
+
+	pub fn keys(&self) -> Vec<String> {
+		self.value
+			.keys()
+			.cloned()
+			.collect()
+	}
+
+	pub fn insert(&mut self, key: &str, value: &str) -> io::Result<()> {
+		self.value.insert(
+			key.to_string(),
+			value.to_string(),
+		);
+
+		self.save()
+	}
+
+	pub fn remove(&mut self, key: &str) -> io::Result<Option<String>> {
+		let value = self.value.remove(key);
+
+		self.save()?;
+
+		Ok(value)
+	}
+
+	pub fn list(&self) -> String {
+		self.value
+			.iter()
+			.map(
+				|(key, value)| {
+					format!(
+						"{key} = {}",
+						script::quote(value)
+					)
+				},
+			)
+			.collect::<Vec<_>>()
+			.join("\n")
+	}
+
+	fn load(&mut self, key: fn(&str) -> String) {
+		let Ok(lines) = script::read(&self.path)
+		else {
+			return;
+		};
+
+		for v in lines {
+			if let Some([bin, name, value]) = shlex::split(&v).as_deref() {
+				if bin.eq(self.bin) {
+					self.value.insert(
+						key(name),
+						value.to_string(),
+					);
+				}
+			}
+		}
+	}
+
+	fn save(&self) -> io::Result<()> {
+		if let Some(directory) = self
+			.path
+			.parent()
+			.filter(|v| !v.as_os_str().is_empty())
+		{
+			fs::create_dir_all(directory)?;
+		}
+
+		let content = self
+			.value
+			.iter()
+			.map(
+				|(key, value)| {
+					format!(
+						"{} {} {}\n",
+						self.bin,
+						script::quote(key),
+						script::quote(value)
+					)
+				},
+			)
+			.collect::<String>();
+
+		fs::write(
+			&self.path, content,
+		)
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::Table;
+	use crate::core::user;
+
+
+	#[test]
+	fn save_and_load() {
+		let path = user::scratch("table").join("table.cfg");
+
+		let mut table = Table::new(
+			"bind",
+			path.clone(),
+			str::to_lowercase,
+		);
+
+		table
+			.insert(
+				"f1", "say 'a b'; help",
+			)
+			.unwrap();
+		table
+			.insert(
+				"f2", "",
+			)
+			.unwrap();
+
+		assert_eq!(
+			table.list(),
+			"f1 = \"say 'a b'; help\"\nf2 = ''"
+		);
+
+		assert_eq!(
+			Table::new(
+				"bind",
+				path.clone(),
+				str::to_lowercase
+			)
+			.list(),
+			table.list()
+		);
+
+		std::fs::write(
+			&path,
+			"bind F1 help\nalias F2 help\n",
+		)
+		.unwrap();
+
+		let table = Table::new(
+			"bind",
+			path.clone(),
+			str::to_lowercase,
+		);
+
+		assert_eq!(
+			table.keys(),
+			vec!["f1"]
+		);
+
+		let mut table = Table::new(
+			"bind",
+			path.clone(),
+			str::to_lowercase,
+		);
+
+		assert_eq!(
+			table
+				.remove("f1")
+				.unwrap(),
+			Some(String::from("help"))
+		);
+
+		assert!(
+			Table::new(
+				"bind",
+				path,
+				str::to_lowercase
+			)
+			.get("f1")
+			.is_none()
+		);
+	}
+}
@@ -0,0 +1,179 @@
+use std::{
+	future::Future,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc, Mutex,
+	},
+};
+
+use bevy::{
+	prelude::Resource,
+	tasks::{block_on, AsyncComputeTaskPool, Task},
+};
+
+use super::{event::Feedback, locale::tr, Level};
+
+#[derive(Clone, Default, Debug)]
+pub struct Progress {
+	cancelled: Arc<AtomicBool>,
+	message:   Arc<Mutex<Option<String>>>,
+}
+
+impl Progress {
+	pub fn report(&self, message: &str) {
+		if let Ok(mut value) = self.message.lock() {
+			*value = message
+				.lines()
+				.next()
+				.map(|v| v.to_string());
+		}
+	}
+
+	pub fn is_cancelled(&self) -> bool {
+		self.cancelled
+			.load(Ordering::Relaxed)
+	}
+
+	fn cancel(&self) {
+		self.cancelled.store(
+			true,
+			Ordering::Relaxed,
+		);
+	}
+
+	fn take(&self) -> Option<String> {
+		self.message
+			.lock()
+			.ok()?
+			.take()
+	}
+}
+
+
+struct Job {
+	id:       usize,
+	bin:      String,
+	progress: Progress,
+	task:     Task<Result<String, String>>,
+}
+
+
+#[derive(Resource, Default)]
+pub struct Tasks {
+	next:  usize,
+	value: Vec<Job>,
+}
+
+impl Tasks {
+	pub fn spawn<F>(&mut self, bin: &str, progress: Progress, future: F)
+	where F: Future<Output = Result<String, String>> + Send + 'static {
+		let task = AsyncComputeTaskPool::get().spawn(future);
+
+		self.next += 1;
+
+		self.value.push(
+			Job {
+				id: self.next,
+				bin: bin.to_string(),
+				progress,
+				task,
+			},
+		);
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.value.is_empty()
+	}
+
+	pub fn contains(&self, id: usize) -> bool {
+		self.value
+			.iter()
+			.any(|v| v.id == id)
+	}
+
+	pub fn is_running(&self, bin: &str) -> bool {
+		self.value
+			.iter()
+			.any(|v| v.bin.eq(bin))
+	}
+
+	pub fn running(&self) -> Vec<String> {
+		self.value
+			.iter()
+			.map(|v| v.bin.clone())
+			.collect()
+	}
+
+	pub fn cancel(&mut self) -> usize {
+		for v in self.value.iter() {
+			v.progress.cancel();
+		}
+
+		self.value.len()
+	}
+
+	pub fn poll(&mut self) -> Vec<Feedback> {
+		let mut feedback = vec![];
+
+		self.value.retain_mut(
+			|job| {
+				let (message, level, running) = if job
+					.progress
+					.is_cancelled()
+				{
+					(
+						tr!("cancelled"),
+						Level::Warn,
+						false,
+					)
+				}
+				else if job.task.is_finished() {
+					match block_on(&mut job.task) {
+						| Ok(message) => {
+							(
+								message,
+								Level::Success,
+								false,
+							)
+						},
+						| Err(message) => {
+							(
+								message,
+								Level::Error,
+								false,
+							)
+						},
+					}
+				}
+				else if let Some(message) = job.progress.take() {
+					(
+						message,
+						Level::Info,
+						true,
+					)
+				}
+				else {
+					return true;
+				};
+
+				let message = format!(
+					"{}: {message}",
+					job.bin
+				);
+
+				feedback.push(
+					Feedback::Replace(
+						job.id,
+						job.bin.clone(),
+						message,
+						level,
+					),
+				);
+
+				running
+			},
+		);
+
+		feedback
+	}
+}
@@ -0,0 +1,269 @@
+use std::{fmt, time::Duration};
+
+use super::locale::tr;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Delay {
+	Frames(u32),
+	Time(Duration),
+}
+
+impl Delay {
+	pub fn parse(input: &str) -> Result<Self, String> {
+		let invalid = || {
+			tr!(
+				"expected a delay like 10f, 500ms or 1.5s, found {input}",
+				input = input
+			)
+		};
+
+		if let Some(frames) = input.strip_suffix('f') {
+			return frames
+				.parse()
+				.map(Delay::Frames)
+				.map_err(|_| invalid());
+		}
+
+		let seconds = if let Some(milliseconds) = input.strip_suffix("ms") {
+			milliseconds
+				.parse::<f64>()
+				.map(|v| v / 1000.0)
+		}
+		else {
+			input
+				.strip_suffix('s')
+				.unwrap_or(input)
+				.parse::<f64>()
+		};
+
+		seconds
+			.ok()
+			.and_then(|v| Duration::try_from_secs_f64(v).ok())
+			.map(Delay::Time)
+			.ok_or_else(invalid)
+	}
+}
+
+impl Default for Delay {
+	fn default() -> Self {
+		Delay::Frames(1)
+	}
+}
+
+impl fmt::Display for Delay {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			| Delay::Frames(v) => {
+				write!(
+					f,
+					"{v}f"
+				)
+			},
+			| Delay::Time(v) => {
+				write!(
+					f,
+					"{}s",
+					v.as_secs_f64()
+				)
+			},
+		}
+	}
+}
+
+
+#[derive(Clone, Copy, Debug)]
+enum Deadline {
+	Frame(u32),
+	Time(Duration),
+}
+
+
+#[derive(Debug)]
+struct Timer {
+	id:       usize,
+	deadline: Deadline,
+	repeat:   Option<Delay>,
+	commands: Vec<(
+		String,
+		usize,
+	)>,
+}
+
+
+#[derive(Default, Debug)]
+pub struct Timers {
+	next:    usize,
+	frame:   u32,
+	elapsed: Duration,
+	value:   Vec<Timer>,
+}
+
+impl Timers {
+	pub fn set_clock(&mut self, frame: u32, elapsed: Duration) {
+		self.frame = frame;
+		self.elapsed = elapsed;
+	}
+
+	pub fn frame(&self) -> u32 {
+		self.frame
+	}
+
+	pub fn elapsed(&self) -> Duration {
+		self.elapsed
+	}
+
+	pub fn insert(
+		&mut self,
+		delay: Delay,
+		repeat: bool,
+		commands: Vec<(
+			String,
+			usize,
+		)>,
+	) -> usize {
+		self.next += 1;
+
+		self.value.push(
+			Timer {
+				id: self.next,
+				deadline: self.deadline(delay),
+				repeat: repeat.then_some(delay),
+				commands,
+			},
+		);
+
+		self.next
+	}
+
+	pub fn remove(&mut self, id: usize) -> bool {
+		let length = self.value.len();
+
+		self.value
+			.retain(|v| v.id != id);
+
+		length != self.value.len()
+	}
+
+	pub fn is_waiting(&self) -> bool {
+		!self.value.is_empty()
+	}
+
+	pub fn is_due(&self) -> bool {
+		self.value
+			.iter()
+			.any(|v| self.reached(v.deadline))
+	}
+
+	pub fn due(
+		&mut self,
+	) -> Vec<
+		Vec<(
+			String,
+			usize,
+		)>,
+	> {
+		let mut due = vec![];
+
+		let mut index = 0;
+
+		while index < self.value.len() {
+			if !self.reached(self.value[index].deadline) {
+				index += 1;
+
+				continue;
+			}
+
+			let timer = &self.value[index];
+
+			due.push(timer.commands.clone());
+
+			if let Some(delay) = timer.repeat {
+				self.value[index].deadline = self.deadline(delay);
+
+				index += 1;
+			}
+			else {
+				self.value.remove(index);
+			}
+		}
+
+		due
+	}
+
+	fn deadline(&self, delay: Delay) -> Deadline {
+		match delay {
+			| Delay::Frames(v) => Deadline::Frame(self.frame + v.max(1)),
+			| Delay::Time(v) => Deadline::Time(self.elapsed + v),
+		}
+	}
+
+	fn reached(&self, deadline: Deadline) -> bool {
+		match deadline {
+			| Deadline::Frame(v) => self.frame >= v,
+			| Deadline::Time(v) => self.elapsed >= v,
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use std::time::Duration;
+
+	use super::Delay;
+
+
+	#[test]
+	fn parse_delay() {
+		assert!(
+			matches!(
+				Delay::parse("10f"),
+				Ok(Delay::Frames(10))
+			)
+		);
+
+		assert!(
+			matches!(
+				Delay::parse("500ms"),
+				Ok(Delay::Time(v)) if v == Duration::from_millis(500)
+			)
+		);
+
+		assert!(
+			matches!(
+				Delay::parse("1.5s"),
+				Ok(Delay::Time(v)) if v == Duration::from_millis(1500)
+			)
+		);
+
+		assert!(
+			matches!(
+				Delay::parse("2"),
+				Ok(Delay::Time(v)) if v == Duration::from_secs(2)
+			)
+		);
+
+		for input in ["", "f", "-1f", "1.5f", "-1s", "abc", "10ms5"] {
+			assert!(
+				Delay::parse(input).is_err(),
+				"{input}"
+			);
+		}
+	}
+
+	#[test]
+	fn display_delay() {
+		assert_eq!(
+			Delay::Frames(3).to_string(),
+			"3f"
+		);
+
+		assert_eq!(
+			Delay::parse("250ms")
+				.unwrap()
+				.to_string(),
+			"0.25s"
+		);
+	}
+}
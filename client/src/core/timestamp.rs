@@ -0,0 +1,59 @@
+use std::{
+	fmt,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct Timestamp(Duration);
+
+impl Timestamp {
+	pub fn now() -> Self {
+		Self(
+			SystemTime::now()
+				.duration_since(UNIX_EPOCH)
+				.unwrap_or_default(),
+		)
+	}
+}
+
+impl fmt::Display for Timestamp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let seconds = self.0.as_secs();
+
+		let (year, month, day) = civil((seconds / 86400) as i64);
+
+		write!(
+			f,
+			"{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+			seconds % 86400 / 3600,
+			seconds % 3600 / 60,
+			seconds % 60,
+			self.0.subsec_millis()
+		)
+	}
+}
+
+fn civil(
+	days: i64,
+) -> (
+	i64,
+	i64,
+	i64,
+) {
+	let days = days + 719468;
+
+	let era = days.div_euclid(146097);
+	let day_of_era = days.rem_euclid(146097);
+	let year_of_era =
+		(day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+	let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+	let month = (5 * day_of_year + 2) / 153;
+
+	let day = day_of_year - (153 * month + 2) / 5 + 1;
+	let month = if month < 10 { month + 3 } else { month - 9 };
+	let year = year_of_era + era * 400 + (month <= 2) as i64;
+
+	(
+		year, month, day,
+	)
+}
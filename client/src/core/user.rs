@@ -0,0 +1,47 @@
+use std::{env, path::PathBuf};
+
+#[cfg(test)]
+thread_local! {
+	static HOME: std::cell::RefCell<Option<PathBuf>> = const { std::cell::RefCell::new(None) };
+}
+
+pub fn path(name: &str) -> PathBuf {
+	#[cfg(test)]
+	if let Some(v) = HOME.with_borrow(Clone::clone) {
+		return v.join(name);
+	}
+
+	let directory = if let Some(v) = env::var_os("SLUGMA_HOME") {
+		PathBuf::from(v)
+	}
+	else if let Some(v) = env::var_os("APPDATA") {
+		PathBuf::from(v).join("slugma")
+	}
+	else if let Some(v) = env::var_os("HOME") {
+		PathBuf::from(v).join(".slugma")
+	}
+	else {
+		PathBuf::from(".")
+	};
+
+	directory.join(name)
+}
+
+#[cfg(test)]
+pub fn scratch(name: &str) -> PathBuf {
+	let directory = env::temp_dir()
+		.join("slugma-test")
+		.join(
+			format!(
+				"{name}-{}",
+				std::process::id()
+			),
+		);
+
+	let _ = std::fs::remove_dir_all(&directory);
+	let _ = std::fs::create_dir_all(&directory);
+
+	HOME.set(Some(directory.clone()));
+
+	directory
+}
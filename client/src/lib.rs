@@ -1,9 +1,11 @@
 mod core;
 mod plugins;
 
+pub mod command;
 pub mod event;
 
 use bevy::app::{PluginGroup, PluginGroupBuilder};
+pub use plugins::log::log_layer;
 
 
 
@@ -11,12 +13,32 @@ pub struct Plugins;
 
 impl PluginGroup for Plugins {
 	fn build(self) -> PluginGroupBuilder {
-		PluginGroupBuilder::start::<Self>()
+		let group = PluginGroupBuilder::start::<Self>()
 			.add(core::Plugin)
 			.add(plugins::character::Plugin)
 			.add(plugins::console::Plugin)
+			.add(plugins::bind::Plugin)
+			.add(plugins::log::Plugin)
+			.add(plugins::inspect::Plugin)
 			.add(plugins::input::Plugin)
 			.add(plugins::fps::Plugin)
-			.add(plugins::window::Plugin)
+			.add(plugins::window::Plugin);
+
+		#[cfg(feature = "remote")]
+		let group = group.add(plugins::remote::Plugin);
+
+		group
+	}
+}
+
+
+pub struct Headless;
+
+impl PluginGroup for Headless {
+	fn build(self) -> PluginGroupBuilder {
+		PluginGroupBuilder::start::<Self>()
+			.add(core::Plugin)
+			.add(plugins::inspect::Plugin)
+			.add(plugins::headless::Plugin)
 	}
 }
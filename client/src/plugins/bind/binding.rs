@@ -0,0 +1,283 @@
+use std::{io, path::PathBuf};
+
+use bevy::{
+	input::{
+		keyboard::{Key, KeyCode},
+		ButtonInput,
+	},
+	prelude::Resource,
+};
+
+use crate::core::{table::Table, user};
+
+const MODIFIER: [(
+	&str,
+	[KeyCode; 2],
+); 4] = [
+	(
+		"ctrl",
+		[KeyCode::ControlLeft, KeyCode::ControlRight],
+	),
+	(
+		"alt",
+		[KeyCode::AltLeft, KeyCode::AltRight],
+	),
+	(
+		"shift",
+		[KeyCode::ShiftLeft, KeyCode::ShiftRight],
+	),
+	(
+		"super",
+		[KeyCode::SuperLeft, KeyCode::SuperRight],
+	),
+];
+
+
+pub fn normalize(name: &str) -> String {
+	let name = name.to_lowercase();
+
+	let mut part = name
+		.split('+')
+		.map(str::trim)
+		.filter(|v| !v.is_empty())
+		.collect::<Vec<_>>();
+
+	let key = part
+		.pop()
+		.unwrap_or_default();
+
+	let mut value = MODIFIER
+		.iter()
+		.map(|(v, _)| *v)
+		.filter(|v| part.contains(v))
+		.collect::<Vec<_>>();
+
+	value.push(key);
+
+	value.join("+")
+}
+
+pub fn name(key: &Key, key_code: KeyCode, pressed: &ButtonInput<KeyCode>) -> Option<String> {
+	let key = match key {
+		| Key::Character(_) if pressed.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) => {
+			unshifted(key_code)
+				.map(String::from)
+				.or_else(|| key_name(key))?
+		},
+
+		| _ => key_name(key)?,
+	};
+
+	let mut value = MODIFIER
+		.iter()
+		.filter(|(_, v)| pressed.any_pressed(*v))
+		.map(|(v, _)| *v)
+		.collect::<Vec<_>>();
+
+	value.push(&key);
+
+	Some(value.join("+"))
+}
+
+fn key_name(key: &Key) -> Option<String> {
+	match key {
+		| Key::Character(v) => Some(v.to_lowercase()),
+		| Key::Space => Some(String::from("space")),
+		| Key::Unidentified(_) | Key::Dead(_) => None,
+		| Key::Control | Key::Alt | Key::Shift | Key::Super => None,
+
+		| v => Some(format!("{v:?}").to_lowercase()),
+	}
+}
+
+fn unshifted(key_code: KeyCode) -> Option<&'static str> {
+	let value = match key_code {
+		| KeyCode::Digit0 => "0",
+		| KeyCode::Digit1 => "1",
+		| KeyCode::Digit2 => "2",
+		| KeyCode::Digit3 => "3",
+		| KeyCode::Digit4 => "4",
+		| KeyCode::Digit5 => "5",
+		| KeyCode::Digit6 => "6",
+		| KeyCode::Digit7 => "7",
+		| KeyCode::Digit8 => "8",
+		| KeyCode::Digit9 => "9",
+		| KeyCode::Backquote => "`",
+		| KeyCode::Minus => "-",
+		| KeyCode::Equal => "=",
+		| KeyCode::BracketLeft => "[",
+		| KeyCode::BracketRight => "]",
+		| KeyCode::Backslash => "\\",
+		| KeyCode::Semicolon => ";",
+		| KeyCode::Quote => "'",
+		| KeyCode::Comma => ",",
+		| KeyCode::Period => ".",
+		| KeyCode::Slash => "/",
+
+		| _ => return None,
+	};
+
+	Some(value)
+}
+
+
+
+#[derive(Resource, Debug)]
+pub struct Binding {
+	table: Table,
+}
+
+impl Binding {
+	pub fn new(path: PathBuf) -> Self {
+		Self {
+			table: Table::new(
+				"bind", path, normalize,
+			),
+		}
+	}
+
+	pub fn get(&self, key: &str) -> Option<&String> {
+		self.table.get(key)
+	}
+
+	pub fn insert(&mut self, key: &str, value: &str) -> io::Result<()> {
+		self.table.insert(
+			key, value,
+		)
+	}
+
+	pub fn remove(&mut self, key: &str) -> io::Result<Option<String>> {
+		self.table.remove(key)
+	}
+
+	pub fn list(&self) -> String {
+		self.table.list()
+	}
+}
+
+impl Default for Binding {
+	fn default() -> Self {
+		Self::new(user::path("bind.cfg"))
+	}
+}
+
+
+
+
+#[cfg(test)]
+mod test {
+	use bevy::input::{
+		keyboard::{Key, KeyCode},
+		ButtonInput,
+	};
+
+	use super::{name, normalize, Binding};
+	use crate::core::user;
+
+
+	fn pressed(value: &[KeyCode]) -> ButtonInput<KeyCode> {
+		let mut input = ButtonInput::default();
+
+		for v in value {
+			input.press(*v);
+		}
+
+		input
+	}
+
+
+	#[test]
+	fn normalize_name() {
+		assert_eq!(
+			normalize("F1"),
+			"f1"
+		);
+
+		assert_eq!(
+			normalize("Shift + Alt+Ctrl+Enter"),
+			"ctrl+alt+shift+enter"
+		);
+
+		assert_eq!(
+			normalize("super+meta+a"),
+			"super+a"
+		);
+	}
+
+	#[test]
+	fn key_name() {
+		assert_eq!(
+			name(
+				&Key::F1,
+				KeyCode::F1,
+				&pressed(&[])
+			),
+			Some(String::from("f1"))
+		);
+
+		assert_eq!(
+			name(
+				&Key::Character("A".into()),
+				KeyCode::KeyA,
+				&pressed(&[KeyCode::ShiftLeft, KeyCode::ControlRight])
+			),
+			Some(String::from("ctrl+shift+a"))
+		);
+
+		assert_eq!(
+			name(
+				&Key::Character("!".into()),
+				KeyCode::Digit1,
+				&pressed(&[KeyCode::ShiftRight])
+			),
+			Some(normalize("shift+1"))
+		);
+
+		assert_eq!(
+			name(
+				&Key::Character("?".into()),
+				KeyCode::Slash,
+				&pressed(&[KeyCode::ShiftLeft])
+			),
+			Some(normalize("shift+/"))
+		);
+
+		assert_eq!(
+			name(
+				&Key::Shift,
+				KeyCode::ShiftLeft,
+				&pressed(&[KeyCode::ShiftLeft])
+			),
+			None
+		);
+	}
+
+	#[test]
+	fn save_and_load() {
+		let path = user::scratch("binding").join("bind.cfg");
+
+		std::fs::write(
+			&path,
+			"bind Shift+Ctrl+F1 help\n",
+		)
+		.unwrap();
+
+		let mut binding = Binding::new(path.clone());
+
+		assert_eq!(
+			binding.get("ctrl+shift+f1"),
+			Some(&String::from("help"))
+		);
+
+		binding
+			.insert(
+				"space", "jump; wait 10f; land",
+			)
+			.unwrap();
+
+		assert_eq!(
+			Binding::new(path).list(),
+			binding.list()
+		);
+	}
+}
@@ -0,0 +1,14 @@
+use bevy::ecs::event::Event;
+
+
+#[derive(Event)]
+pub enum Bind {
+	Insert(
+		String,
+		String,
+	),
+	Remove(String),
+	Show(String),
+
+	List,
+}
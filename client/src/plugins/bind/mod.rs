@@ -0,0 +1,224 @@
+pub mod binding;
+pub mod event;
+
+use bevy::{
+	app::{App, Update},
+	ecs::{
+		event::{EventReader, EventWriter},
+		schedule::common_conditions,
+		system::{Res, ResMut},
+	},
+	input::{
+		keyboard::{KeyCode, KeyboardInput},
+		ButtonInput,
+	},
+	prelude::IntoSystemConfigs,
+	state::condition,
+};
+use binding::Binding;
+
+use super::console::state::Console;
+use crate::core::{event::Feedback, locale::tr, Actuator, AddCommandEvent, Level};
+
+pub fn new_bind() -> clap::Command {
+	clap::Command::new("bind")
+		.about("bind a key to a command")
+		.arg(clap::arg!(<key> "key name, e.g. f1 or ctrl+alt+enter"))
+		.arg(clap::arg!([command] "command line"))
+}
+
+pub fn new_unbind() -> clap::Command {
+	clap::Command::new("unbind")
+		.about("remove a key binding")
+		.arg(clap::arg!(<key> "key name"))
+}
+
+pub fn new_bindlist() -> clap::Command {
+	clap::Command::new("bindlist").about("list the key bindings")
+}
+
+pub fn parse_bind(arg: clap::ArgMatches) -> Option<event::Bind> {
+	let key = binding::normalize(arg.get_one::<String>("key")?);
+
+	match arg.get_one::<String>("command") {
+		| Some(command) => {
+			Some(
+				event::Bind::Insert(
+					key,
+					command.clone(),
+				),
+			)
+		},
+
+		| None => Some(event::Bind::Show(key)),
+	}
+}
+
+pub fn parse_unbind(arg: clap::ArgMatches) -> Option<event::Bind> {
+	let key = binding::normalize(arg.get_one::<String>("key")?);
+
+	Some(event::Bind::Remove(key))
+}
+
+pub fn parse_bindlist(_: clap::ArgMatches) -> Option<event::Bind> {
+	Some(event::Bind::List)
+}
+
+pub fn update(
+	mut binding: ResMut<Binding>,
+	mut bind_event: EventReader<event::Bind>,
+	mut feedback: EventWriter<Feedback>,
+) {
+	for v in bind_event.read() {
+		let source = match v {
+			| event::Bind::Insert(..) | event::Bind::Show(_) => "bind",
+			| event::Bind::Remove(_) => "unbind",
+			| event::Bind::List => "bindlist",
+		};
+
+		let result = match v {
+			| event::Bind::Insert(key, command) => {
+				binding
+					.insert(
+						key, command,
+					)
+					.map(|_| None)
+			},
+			| event::Bind::Remove(key) => {
+				binding.remove(key).map(
+					|v| {
+						v.is_none().then(
+							|| {
+								(
+									tr!(
+										"{key} is not bound",
+										key = key
+									),
+									Level::Error,
+								)
+							},
+						)
+					},
+				)
+			},
+			| event::Bind::Show(key) => {
+				let message = match binding.get(key) {
+					| Some(command) => {
+						(
+							format!("{key} = {command}"),
+							Level::Success,
+						)
+					},
+					| None => {
+						(
+							tr!(
+								"{key} is not bound",
+								key = key
+							),
+							Level::Error,
+						)
+					},
+				};
+
+				Ok(Some(message))
+			},
+
+			| event::Bind::List => {
+				Ok(
+					Some(
+						(
+							binding.list(),
+							Level::Success,
+						),
+					),
+				)
+			},
+		};
+
+		match result {
+			| Ok(Some((message, level))) => {
+				feedback.send(
+					Feedback::Append(
+						String::from(source),
+						message,
+						level,
+					),
+				);
+			},
+			| Ok(None) => {},
+			| Err(error) => {
+				feedback.send(
+					Feedback::Append(
+						String::from(source),
+						tr!(
+							"cannot save bindings: {error}",
+							error = error
+						),
+						Level::Warn,
+					),
+				);
+			},
+		}
+
+		feedback.send(Feedback::Done(String::from(source)));
+	}
+}
+
+pub fn trigger(
+	binding: Res<Binding>,
+	key_code: Res<ButtonInput<KeyCode>>,
+	mut actuator: ResMut<Actuator>,
+	mut keyboard_input: EventReader<KeyboardInput>,
+) {
+	for v in keyboard_input.read() {
+		if !v.state.is_pressed() || !key_code.just_pressed(v.key_code) {
+			continue;
+		}
+
+		let Some(name) = binding::name(
+			&v.logical_key, v.key_code, &key_code,
+		)
+		else {
+			continue;
+		};
+
+		if let Some(command) = binding.get(&name) {
+			actuator.enqueue(
+				command, 0,
+			);
+		}
+	}
+}
+
+
+pub struct Plugin;
+
+impl bevy::app::Plugin for Plugin {
+	fn build(&self, app: &mut App) {
+		app.init_resource::<Binding>();
+
+		app.add_command_event(
+			new_bind(),
+			parse_bind,
+		);
+		app.add_command_event(
+			new_unbind(),
+			parse_unbind,
+		);
+		app.add_command_event(
+			new_bindlist(),
+			parse_bindlist,
+		);
+
+
+		app.add_systems(
+			Update,
+			(
+				update.run_if(common_conditions::on_event::<event::Bind>()),
+				trigger
+					.run_if(common_conditions::on_event::<KeyboardInput>())
+					.run_if(condition::in_state(Console::Close)),
+			),
+		);
+	}
+}
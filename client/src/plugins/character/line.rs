@@ -0,0 +1,300 @@
+#[derive(Default, Debug)]
+pub struct Line {
+	value:  String,
+	cursor: usize,
+}
+
+impl Line {
+	pub fn value(&self) -> &str {
+		&self.value
+	}
+
+	pub fn cursor(&self) -> usize {
+		self.cursor
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.value.is_empty()
+	}
+
+	pub fn set(&mut self, value: &str) {
+		self.value = value.to_string();
+		self.cursor = self.value.len();
+	}
+
+	pub fn clear(&mut self) {
+		self.value.clear();
+		self.cursor = 0;
+	}
+
+	pub fn insert(&mut self, text: &str) {
+		self.value.insert_str(
+			self.cursor, text,
+		);
+
+		self.cursor += text.len();
+	}
+
+	pub fn backspace(&mut self) {
+		let start = self.previous();
+
+		self.value
+			.replace_range(
+				start..self.cursor,
+				"",
+			);
+
+		self.cursor = start;
+	}
+
+	pub fn delete(&mut self) {
+		let end = self.next();
+
+		self.value
+			.replace_range(
+				self.cursor..end,
+				"",
+			);
+	}
+
+	pub fn delete_word(&mut self) {
+		let start = self.word_start();
+
+		self.value
+			.replace_range(
+				start..self.cursor,
+				"",
+			);
+
+		self.cursor = start;
+	}
+
+	pub fn delete_to_start(&mut self) {
+		self.value
+			.replace_range(
+				..self.cursor,
+				"",
+			);
+
+		self.cursor = 0;
+	}
+
+	pub fn left(&mut self) {
+		self.cursor = self.previous();
+	}
+
+	pub fn right(&mut self) {
+		self.cursor = self.next();
+	}
+
+	pub fn home(&mut self) {
+		self.cursor = 0;
+	}
+
+	pub fn end(&mut self) {
+		self.cursor = self.value.len();
+	}
+
+	pub fn word_left(&mut self) {
+		self.cursor = self.word_start();
+	}
+
+	pub fn word_right(&mut self) {
+		let tail = &self.value[self.cursor..];
+
+		let skip = tail.len() - tail.trim_start().len();
+
+		self.cursor += tail[skip..]
+			.find(char::is_whitespace)
+			.map_or(
+				tail.len(),
+				|v| skip + v,
+			);
+	}
+
+	fn previous(&self) -> usize {
+		self.value[..self.cursor]
+			.chars()
+			.next_back()
+			.map_or(
+				self.cursor,
+				|v| self.cursor - v.len_utf8(),
+			)
+	}
+
+	fn next(&self) -> usize {
+		self.value[self.cursor..]
+			.chars()
+			.next()
+			.map_or(
+				self.cursor,
+				|v| self.cursor + v.len_utf8(),
+			)
+	}
+
+	fn word_start(&self) -> usize {
+		let head = self.value[..self.cursor].trim_end();
+
+		head.char_indices()
+			.rfind(|(_, v)| v.is_whitespace())
+			.map_or(
+				0,
+				|(i, v)| i + v.len_utf8(),
+			)
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::Line;
+
+
+	fn line(value: &str, cursor: usize) -> Line {
+		let mut line = Line::default();
+
+		line.set(value);
+		line.cursor = cursor;
+
+		line
+	}
+
+
+	#[test]
+	fn insert_and_delete() {
+		let mut line = line(
+			"héllo", 3,
+		);
+
+		line.insert("y");
+
+		assert_eq!(
+			line.value(),
+			"héyllo"
+		);
+
+		line.backspace();
+		line.backspace();
+
+		assert_eq!(
+			(
+				line.value(),
+				line.cursor()
+			),
+			("hllo", 1)
+		);
+
+		line.delete();
+
+		assert_eq!(
+			line.value(),
+			"hlo"
+		);
+
+		line.end();
+		line.delete();
+		line.home();
+		line.backspace();
+
+		assert_eq!(
+			(
+				line.value(),
+				line.cursor()
+			),
+			("hlo", 0)
+		);
+	}
+
+	#[test]
+	fn move_cursor() {
+		let mut line = line(
+			"a é", 0,
+		);
+
+		line.right();
+		line.right();
+		line.right();
+
+		assert_eq!(
+			line.cursor(),
+			4
+		);
+
+		line.right();
+		line.left();
+
+		assert_eq!(
+			line.cursor(),
+			2
+		);
+	}
+
+	#[test]
+	fn move_by_word() {
+		let mut line = line(
+			"set  cheats 1", 0,
+		);
+
+		line.word_right();
+
+		assert_eq!(
+			line.cursor(),
+			3
+		);
+
+		line.word_right();
+
+		assert_eq!(
+			line.cursor(),
+			11
+		);
+
+		line.word_left();
+
+		assert_eq!(
+			line.cursor(),
+			5
+		);
+
+		line.word_left();
+		line.word_left();
+
+		assert_eq!(
+			line.cursor(),
+			0
+		);
+	}
+
+	#[test]
+	fn delete_words() {
+		let mut line = line(
+			"set cheats 1", 10,
+		);
+
+		line.delete_word();
+
+		assert_eq!(
+			(
+				line.value(),
+				line.cursor()
+			),
+			("set  1", 4)
+		);
+
+		line.delete_to_start();
+
+		assert_eq!(
+			(
+				line.value(),
+				line.cursor()
+			),
+			(" 1", 0)
+		);
+
+		line.set("set ");
+		line.delete_word();
+
+		assert!(line.is_empty());
+	}
+}
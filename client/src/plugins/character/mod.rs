@@ -1,46 +1,63 @@
 pub mod event;
+pub mod line;
 
 use bevy::{
 	app::App,
 	ecs::{
 		event::{EventReader, EventWriter},
-		system::Local,
+		system::{Local, Res},
 	},
-	input::keyboard::{Key, KeyboardInput},
+	input::{
+		keyboard::{Key, KeyCode, KeyboardInput},
+		ButtonInput,
+	},
+	window::Ime,
 };
 use event::Update;
+use line::Line;
 
 
 
 
 pub fn setup(
 	mut keyboard_input: EventReader<KeyboardInput>,
+	mut ime: EventReader<Ime>,
 	mut update_character: EventWriter<Update>,
 ) {
 	keyboard_input.clear();
+	ime.clear();
 	update_character.send(Update(String::from("")));
 }
 
 pub fn cleanup(
 	mut keyboard_input: EventReader<KeyboardInput>,
+	mut ime: EventReader<Ime>,
 	mut update_character: EventWriter<Update>,
 ) {
 	keyboard_input.clear();
+	ime.clear();
 	update_character.send(Update(String::from("")));
 }
 
 pub fn received(
-	mut character: Local<String>,
+	mut line: Local<Line>,
 	mut update_character: EventReader<Update>,
 	mut keyboard_input: EventReader<KeyboardInput>,
+	mut ime: EventReader<Ime>,
+	key_code: Res<ButtonInput<KeyCode>>,
 ) -> (
 	String,
+	usize,
 	bool,
 ) {
 	let mut enter = false;
 
+	let ctrl = key_code.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+
+	let alt = key_code.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]);
+
 	for v in update_character.read() {
-		*character = v.0.to_string();
+		line.set(&v.0);
 	}
 
 	for v in keyboard_input.read() {
@@ -54,36 +71,78 @@ pub fn received(
 			},
 
 			| Key::Escape => {
-				if !character.is_empty() {
-					character.clear();
+				if !line.is_empty() {
+					line.clear();
 				}
 			},
 
-			| Key::Backspace | Key::Delete => {
-				character.pop();
+			| Key::Backspace => {
+				line.backspace();
+			},
+
+			| Key::Delete => {
+				line.delete();
+			},
+
+			| Key::ArrowLeft if ctrl => {
+				line.word_left();
+			},
+
+			| Key::ArrowRight if ctrl => {
+				line.word_right();
+			},
+
+			| Key::ArrowLeft => {
+				line.left();
+			},
+
+			| Key::ArrowRight => {
+				line.right();
+			},
+
+			| Key::Home => {
+				line.home();
+			},
+
+			| Key::End => {
+				line.end();
 			},
 
 			| Key::Space => {
-				character.push(' ');
+				line.insert(" ");
 			},
 
-			| Key::Character(input) => {
-				let text = input.to_string();
+			| Key::Character(_) if ctrl && !alt => {
+				match v.key_code {
+					| KeyCode::KeyW => line.delete_word(),
+					| KeyCode::KeyU => line.delete_to_start(),
+
+					| _ => {},
+				}
+			},
 
-				character.push_str(&text);
+			| Key::Character(input) => {
+				line.insert(input);
 			},
 
 			| _ => {},
 		}
 	}
 
+	for v in ime.read() {
+		if let Ime::Commit { value, .. } = v {
+			line.insert(value);
+		}
+	}
+
 	let value = (
-		character.to_string(),
+		line.value().to_string(),
+		line.cursor(),
 		enter,
 	);
 
 	if enter {
-		character.clear();
+		line.clear();
 	}
 
 	value
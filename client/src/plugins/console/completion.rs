@@ -0,0 +1,51 @@
+use bevy::{
+	input::ButtonInput,
+	prelude::{EventWriter, KeyCode, Query, ResMut},
+};
+
+use super::{event::PromptRefresh, prompt::Prompt};
+use crate::{
+	core::{event::Refresh, Actuator, Level},
+	plugins::character::event::Update,
+};
+
+pub fn complete(
+	mut actuator: ResMut<Actuator>,
+	mut key_code: ResMut<ButtonInput<KeyCode>>,
+	prompt: Query<&Prompt>,
+	mut prompt_refresh: EventWriter<PromptRefresh>,
+	mut character_update: EventWriter<Update>,
+	mut refresh: EventWriter<Refresh>,
+) {
+	if key_code.just_pressed(KeyCode::Tab) {
+		key_code.clear();
+
+		let prompt = prompt.single();
+
+		let completion = actuator.complete(&prompt.0);
+
+		if completion
+			.candidates
+			.len() > 1
+		{
+			actuator.push_record(
+				&format!(
+					"$ {}",
+					prompt.0
+				),
+				Level::Display,
+			);
+			actuator.push_record(
+				&completion
+					.candidates
+					.join("    "),
+				Level::Display,
+			);
+
+			refresh.send(Refresh);
+		}
+
+		prompt_refresh.send(PromptRefresh::new(completion.value.clone()));
+		character_update.send(Update(completion.value));
+	}
+}
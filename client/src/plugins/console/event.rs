@@ -1,7 +1,20 @@
 use bevy::ecs::event::Event;
 
 #[derive(Event)]
-pub(crate) struct PromptRefresh(pub String);
+pub(crate) struct PromptRefresh(
+	pub String,
+	pub usize,
+);
+
+impl PromptRefresh {
+	pub fn new(value: String) -> Self {
+		let cursor = value.len();
+
+		Self(
+			value, cursor,
+		)
+	}
+}
 
 #[derive(Event)]
 pub(crate) struct PanelRefresh;
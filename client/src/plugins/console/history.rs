@@ -16,7 +16,7 @@ pub fn roll_back(
 		key_code.clear();
 
 		if let Some(message) = actuator.roll_back_history() {
-			prompt_refresh.send(PromptRefresh(message.clone()));
+			prompt_refresh.send(PromptRefresh::new(message.clone()));
 			character_update.send(Update(message.clone()));
 		}
 	}
@@ -38,7 +38,7 @@ pub fn roll_forward(
 
 		key_code.clear();
 
-		prompt_refresh.send(PromptRefresh(message.clone()));
+		prompt_refresh.send(PromptRefresh::new(message.clone()));
 		character_update.send(Update(message.clone()));
 	}
 }
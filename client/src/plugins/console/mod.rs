@@ -1,13 +1,18 @@
+mod completion;
 mod event;
 mod history;
 mod panel;
 mod prompt;
+mod search;
+mod slide;
 mod ui;
+mod wrap;
 
 pub mod state;
 
 use bevy::{
 	app::{App, Update},
+	asset::AssetEvent,
 	ecs::schedule::common_conditions,
 	input::{keyboard::KeyboardInput, mouse::MouseWheel},
 	prelude::{
@@ -15,13 +20,18 @@ use bevy::{
 		OnExit, SystemSet,
 	},
 	state::condition,
-	window::WindowResized,
+	text::Font,
+	window::{Ime, WindowResized},
 };
 use event::{PanelRefresh, PromptRefresh};
 use state::Console;
 
 use super::character;
-use crate::core::{self, event::Refresh};
+use crate::core::{
+	self,
+	event::{CvarChanged, Refresh},
+	AddCvar,
+};
 
 
 
@@ -46,6 +56,13 @@ impl bevy::app::Plugin for Plugin {
 
 		app.add_event::<character::event::Update>();
 
+		app.init_resource::<search::Search>();
+		app.init_resource::<slide::Slide>();
+
+
+		app.add_cvar(slide::height());
+		app.add_cvar(slide::speed());
+
 
 		app.configure_sets(
 			Update,
@@ -70,6 +87,7 @@ impl bevy::app::Plugin for Plugin {
 			OnExit(Console::Open),
 			(
 				ui::cleanup,
+				search::cleanup,
 				character::cleanup,
 			),
 		);
@@ -82,6 +100,11 @@ impl bevy::app::Plugin for Plugin {
 				.run_if(condition::in_state(Console::Close)),
 		);
 
+		app.add_systems(
+			Update,
+			slide::animate.run_if(common_conditions::any_with_component::<panel::Panel>),
+		);
+
 		app.add_systems(
 			Update,
 			character::received
@@ -89,7 +112,8 @@ impl bevy::app::Plugin for Plugin {
 				.before(core::execute)
 				.run_if(
 					common_conditions::on_event::<KeyboardInput>()
-						.or_else(common_conditions::on_event::<character::event::Update>()),
+						.or_else(common_conditions::on_event::<character::event::Update>())
+						.or_else(common_conditions::on_event::<Ime>()),
 				)
 				.in_set(Step::Receive),
 		);
@@ -99,6 +123,10 @@ impl bevy::app::Plugin for Plugin {
 			(
 				(
 					ui::close,
+					ui::interrupt,
+					completion::complete,
+					search::start,
+					search::cancel,
 					history::roll_back,
 					history::roll_forward,
 					panel::scroll_up,
@@ -106,7 +134,19 @@ impl bevy::app::Plugin for Plugin {
 				)
 					.run_if(common_conditions::on_event::<KeyboardInput>()),
 				panel::scroll_wheel.run_if(common_conditions::on_event::<MouseWheel>()),
-				panel::resize.run_if(common_conditions::on_event::<WindowResized>()),
+				(
+					panel::resize,
+					panel::relabel,
+				)
+					.chain()
+					.run_if(common_conditions::on_event::<WindowResized>()),
+				panel::reflow.run_if(common_conditions::on_event::<AssetEvent<Font>>()),
+				(
+					slide::sync,
+					panel::relabel,
+				)
+					.chain()
+					.run_if(common_conditions::on_event::<CvarChanged>()),
 			)
 				.in_set(Step::Update),
 		);
@@ -120,7 +160,10 @@ impl bevy::app::Plugin for Plugin {
 				panel::refresh
 					.after(panel::refresh_transfer)
 					.run_if(common_conditions::on_event::<PanelRefresh>()),
-				prompt::refresh.run_if(common_conditions::on_event::<PromptRefresh>()),
+				prompt::refresh.run_if(
+					common_conditions::on_event::<PromptRefresh>()
+						.or_else(common_conditions::on_event::<Ime>()),
+				),
 			)
 				.in_set(Step::Refresh),
 		);
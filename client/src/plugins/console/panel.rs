@@ -1,16 +1,21 @@
 use bevy::{
+	asset::{AssetEvent, Assets},
 	color::Color,
 	input::{
 		mouse::{MouseScrollUnit, MouseWheel},
 		ButtonInput,
 	},
-	prelude::{Component, EventReader, EventWriter, KeyCode, Query, Res, ResMut, TextBundle, With},
-	text::{Text, TextStyle},
+	prelude::{
+		BuildChildren, Commands, Component, DespawnRecursiveExt, Entity, EventReader, EventWriter,
+		KeyCode, Query, Res, ResMut, TextBundle, With,
+	},
+	text::{Font, Text, TextStyle},
+	ui::{UiRect, Val},
 	window::WindowResized,
 };
 
-use super::event::PanelRefresh;
-use crate::core::{event::Refresh, Actuator, Level};
+use super::{event::PanelRefresh, slide::Slide, wrap};
+use crate::core::{event::Refresh, Actuator, Entry, Level};
 
 
 #[derive(Component)]
@@ -20,13 +25,14 @@ pub struct Label;
 #[derive(Component, Default)]
 pub(crate) struct Panel {
 	pub style:       TextStyle,
-	pub last_index:  Option<usize>,
+	pub offset:      usize,
 	pub scroll_step: usize,
 	pub max_display: usize,
+	pub max_width:   f32,
 }
 
 impl Panel {
-	pub(crate) fn new(height: f32, style: TextStyle) -> Self {
+	pub(crate) fn new(width: f32, height: f32, style: TextStyle) -> Self {
 		let mut panel = Self {
 			style,
 
@@ -34,12 +40,13 @@ impl Panel {
 		};
 
 		panel.limit_display(height);
+		panel.limit_width(width);
 
 		panel
 	}
 
 	pub fn bubble(&mut self) {
-		self.last_index = None;
+		self.offset = 0;
 	}
 
 	pub fn limit_display(&mut self, height: f32) {
@@ -48,7 +55,56 @@ impl Panel {
 		let max_display = display as usize;
 
 		self.max_display = max_display;
-		self.scroll_step = max_display / 10;
+		self.scroll_step = (max_display / 10).max(1);
+	}
+
+	pub fn limit_width(&mut self, width: f32) {
+		self.max_width = width - 30.0;
+	}
+
+	pub fn rows(
+		&self,
+		fonts: &Assets<Font>,
+		entries: Vec<&Entry>,
+	) -> Vec<(
+		String,
+		Level,
+	)> {
+		let font = fonts.get(&self.style.font);
+
+		let mut rows = vec![];
+
+		for entry in entries
+			.into_iter()
+			.rev()
+		{
+			if rows.len() >= self.offset + self.max_display {
+				break;
+			}
+
+			let wrapped = match font {
+				| Some(font) => {
+					wrap::wrap(
+						font, self.style.font_size, self.max_width, &entry.message,
+					)
+				},
+				| None => vec![entry.message.clone()],
+			};
+
+			for v in wrapped
+				.into_iter()
+				.rev()
+			{
+				rows.push(
+					(
+						v,
+						entry.level.clone(),
+					),
+				);
+			}
+		}
+
+		rows
 	}
 
 	pub fn dyeing(&self, level: &Level) -> Color {
@@ -77,7 +133,7 @@ impl Panel {
 		}
 	}
 
-	pub fn from_section(&self) -> TextBundle {
+	pub fn label_bundle(&self) -> TextBundle {
 		let color = self.dyeing(&Level::Display);
 
 		TextBundle::from_section(
@@ -87,13 +143,38 @@ impl Panel {
 				..self.style.clone()
 			},
 		)
+		.with_no_wrap()
+	}
+
+	pub fn labels(&self, commands: &mut Commands) -> Vec<Entity> {
+		let mut label = Vec::new();
+
+		for _ in 0..self.max_display {
+			let mut label_text = self.label_bundle();
+
+			label_text
+				.style
+				.min_height = Val::Px(10.0);
+			label_text.style.margin = UiRect::left(Val::Px(10.0));
+
+			let id = commands
+				.spawn(
+					(
+						Label, label_text,
+					),
+				)
+				.id();
+
+			label.push(id);
+		}
+
+		label
 	}
 }
 
 
 
 pub fn scroll_up(
-	actuator: Res<Actuator>,
 	mut key_code: ResMut<ButtonInput<KeyCode>>,
 	mut panel: Query<&mut Panel>,
 	mut panel_refresh: EventWriter<PanelRefresh>,
@@ -101,11 +182,9 @@ pub fn scroll_up(
 	if key_code.just_pressed(KeyCode::PageUp) {
 		let mut panel = panel.single_mut();
 
-		let last_index = panel
-			.last_index
-			.unwrap_or_else(|| actuator.record_last_index());
-
-		panel.last_index = Some(last_index.saturating_sub(panel.scroll_step));
+		panel.offset = panel
+			.offset
+			.saturating_add(panel.scroll_step);
 
 		panel_refresh.send(PanelRefresh);
 
@@ -122,12 +201,9 @@ pub fn scroll_down(
 	if key_code.just_pressed(KeyCode::PageDown) {
 		let mut panel = panel.single_mut();
 
-		panel.last_index = if let Some(v) = panel.last_index {
-			Some(v.saturating_add(panel.scroll_step))
-		}
-		else {
-			None
-		};
+		panel.offset = panel
+			.offset
+			.saturating_sub(panel.scroll_step);
 
 		panel_refresh.send(PanelRefresh);
 
@@ -137,41 +213,37 @@ pub fn scroll_down(
 
 
 pub fn scroll_wheel(
-	actuator: Res<Actuator>,
 	mut mouse_wheel: EventReader<MouseWheel>,
 	mut panel: Query<&mut Panel>,
 	mut panel_refresh: EventWriter<PanelRefresh>,
 ) {
 	let mut panel = panel.single_mut();
 
-	let mut last_index = if let Some(v) = panel.last_index {
-		v
-	}
-	else {
-		actuator.record_last_index()
-	};
+	let mut offset = panel.offset;
 
 	for v in mouse_wheel.read() {
 		match v.unit {
 			| MouseScrollUnit::Line => {
 				if v.y > 0.0 {
-					last_index = last_index.saturating_sub(panel.scroll_step);
+					offset = offset.saturating_add(panel.scroll_step);
 				}
 
 				if v.y < 0.0 {
-					last_index = last_index.saturating_add(panel.scroll_step);
+					offset = offset.saturating_sub(panel.scroll_step);
 				}
 			},
 			| _ => (),
 		}
 	}
 
-	panel.last_index = Some(last_index);
+	panel.offset = offset;
 
 	panel_refresh.send(PanelRefresh);
 }
 
 pub fn resize(
+	actuator: Res<Actuator>,
+	slide: Res<Slide>,
 	mut panel: Query<&mut Panel>,
 	mut window_resized: EventReader<WindowResized>,
 	mut panel_refresh: EventWriter<PanelRefresh>,
@@ -179,14 +251,60 @@ pub fn resize(
 	let mut panel = panel.single_mut();
 
 	for v in window_resized.read() {
-		panel.limit_display(v.height);
+		panel.limit_display(v.height * slide.height(&actuator));
+		panel.limit_width(v.width);
 	}
 
 	panel_refresh.send(PanelRefresh);
 }
 
+pub fn relabel(
+	mut commands: Commands,
+	panel: Query<(
+		Entity,
+		&Panel,
+	)>,
+	label: Query<Entity, With<Label>>,
+) {
+	let (root, panel) = panel.single();
+
+	if label.iter().count() == panel.max_display {
+		return;
+	}
+
+	for id in label.iter() {
+		commands
+			.entity(id)
+			.despawn_recursive();
+	}
+
+	let label = panel.labels(&mut commands);
+
+	commands
+		.entity(root)
+		.insert_children(
+			0, &label,
+		);
+}
+
+pub fn reflow(
+	mut font: EventReader<AssetEvent<Font>>,
+	panel: Query<&Panel>,
+	mut panel_refresh: EventWriter<PanelRefresh>,
+) {
+	let panel = panel.single();
+
+	if font
+		.read()
+		.any(|v| v.is_loaded_with_dependencies(&panel.style.font))
+	{
+		panel_refresh.send(PanelRefresh);
+	}
+}
+
 pub fn refresh(
 	actuator: Res<Actuator>,
+	fonts: Res<Assets<Font>>,
 	mut panel: Query<&mut Panel>,
 	mut label: Query<&mut Text, With<Label>>,
 	mut panel_refresh: EventReader<PanelRefresh>,
@@ -195,32 +313,26 @@ pub fn refresh(
 
 	let mut panel = panel.single_mut();
 
-	if panel.max_display >= actuator.record_len() {
-		panel.last_index = None;
-	}
+	let rows = panel.rows(
+		&fonts,
+		actuator.visible_record(),
+	);
 
-	if let Some(v) = panel.last_index {
-		panel.last_index = if actuator.record_last_index() > v {
-			let first_index = panel
-				.max_display
-				.saturating_sub(1);
+	panel.offset = panel.offset.min(
+		rows.len()
+			.saturating_sub(panel.max_display),
+	);
 
-			Some(v.max(first_index))
-		}
-		else {
-			None
-		}
-	}
+	let count = label.iter().count();
 
-	let item: Vec<_> = actuator
-		.get_record(
-			&panel.last_index, &panel.max_display,
-		)
-		.collect();
+	let item = rows
+		.iter()
+		.skip(panel.offset)
+		.take(count)
+		.rev()
+		.collect::<Vec<_>>();
 
-	let start = panel
-		.max_display
-		.saturating_sub(item.len());
+	let start = count.saturating_sub(item.len());
 
 	for (i, mut v) in label
 		.iter_mut()
@@ -233,12 +345,7 @@ pub fn refresh(
 			)
 		}
 		else {
-			let (text, level) = item[i.saturating_sub(start)];
-
-			(
-				text.clone(),
-				level.clone(),
-			)
+			item[i.saturating_sub(start)].clone()
 		};
 
 		let color = panel.dyeing(&level);
@@ -1,25 +1,83 @@
 use bevy::{
-	prelude::{Component, EventReader, Query, With},
+	color::Color,
+	prelude::{Component, EventReader, Local, Query, Res},
 	text::Text,
+	window::Ime,
 };
 
-use super::event::PromptRefresh;
+use super::{event::PromptRefresh, search::Search};
 
-#[derive(Component)]
-pub struct Prompt;
+pub const CURSOR: Color = Color::srgb(
+	1.0, 0.8, 0.2,
+);
+
+pub const PREEDIT: Color = Color::srgb(
+	0.6, 0.8, 1.0,
+);
+
+
+#[derive(Component, Default)]
+pub struct Prompt(
+	pub String,
+	pub usize,
+);
 
 
 
 pub fn refresh(
-	mut prompt: Query<&mut Text, With<Prompt>>,
+	mut prompt: Query<(
+		&mut Prompt,
+		&mut Text,
+	)>,
+	search: Res<Search>,
+	mut preedit: Local<String>,
 	mut prompt_refresh: EventReader<PromptRefresh>,
+	mut ime: EventReader<Ime>,
 ) {
-	let mut text = prompt.single_mut();
+	let (mut prompt, mut text) = prompt.single_mut();
+
+	for v in ime.read() {
+		match v {
+			| Ime::Preedit { value, .. } => *preedit = value.clone(),
+			| Ime::Commit { .. } | Ime::Disabled { .. } => preedit.clear(),
+
+			| _ => {},
+		}
+	}
+
+	for v in prompt_refresh
+		.read()
+		.filter(|_| !search.active)
+	{
+		prompt.0 = v.0.clone();
+		prompt.1 = v.1.min(v.0.len());
+	}
+
+	if search.active {
+		text.sections[0].value = search.prompt();
+		text.sections[1].value = preedit.clone();
+		text.sections[2].value = String::new();
+		text.sections[3].value = String::new();
 
-	for v in prompt_refresh.read() {
-		text.sections[0].value = format!(
-			"$ {}_",
-			v.0.to_string()
-		);
+		return;
 	}
+
+	let (head, tail) = prompt
+		.0
+		.split_at(prompt.1);
+
+	let mut tail = tail.chars();
+
+	let current = match tail.next() {
+		| Some(v) if !v.is_whitespace() => v.to_string(),
+
+		| _ => String::from("_"),
+	};
+
+	text.sections[0].value = format!("$ {head}");
+	text.sections[1].value = preedit.clone();
+	text.sections[2].value = current;
+	text.sections[3].value = tail
+		.as_str()
+		.to_string();
 }
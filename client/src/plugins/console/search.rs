@@ -0,0 +1,109 @@
+use bevy::{
+	input::ButtonInput,
+	prelude::{EventWriter, KeyCode, Query, Res, ResMut, Resource},
+};
+
+use super::{event::PromptRefresh, prompt::Prompt};
+use crate::{
+	core::{locale::tr, Actuator},
+	plugins::character::event::Update,
+};
+
+#[derive(Resource, Default, Debug)]
+pub struct Search {
+	pub active:   bool,
+	pub original: String,
+	pub query:    String,
+	pub index:    Option<usize>,
+	pub found:    String,
+}
+
+impl Search {
+	pub fn find(&mut self, actuator: &Actuator, query: &str, before: Option<usize>) {
+		self.query = query.to_string();
+
+		if let Some((index, found)) = actuator.search_history(
+			query, before,
+		) {
+			self.index = Some(index);
+			self.found = found.clone();
+		}
+		else if before.is_none() {
+			self.index = None;
+			self.found.clear();
+		}
+	}
+
+	pub fn prompt(&self) -> String {
+		tr!(
+			"(reverse-i-search)`{query}': {found}",
+			query = self.query,
+			found = self.found
+		)
+	}
+}
+
+
+
+pub fn start(
+	actuator: Res<Actuator>,
+	mut key_code: ResMut<ButtonInput<KeyCode>>,
+	prompt: Query<&Prompt>,
+	mut search: ResMut<Search>,
+	mut prompt_refresh: EventWriter<PromptRefresh>,
+	mut character_update: EventWriter<Update>,
+) {
+	let ctrl = key_code.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+
+	if ctrl && key_code.just_pressed(KeyCode::KeyR) {
+		key_code.clear();
+
+		if search.active {
+			let query = search.query.clone();
+			let before = search.index;
+
+			search.find(
+				&actuator, &query, before,
+			);
+		}
+		else {
+			*search = Search {
+				active: true,
+				original: prompt
+					.single()
+					.0
+					.clone(),
+
+				..Default::default()
+			};
+
+			character_update.send(Update(String::from("")));
+		}
+
+		prompt_refresh.send(PromptRefresh::new(search.query.clone()));
+	}
+}
+
+pub fn cancel(
+	mut key_code: ResMut<ButtonInput<KeyCode>>,
+	mut search: ResMut<Search>,
+	mut prompt_refresh: EventWriter<PromptRefresh>,
+	mut character_update: EventWriter<Update>,
+) {
+	let ctrl = key_code.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+
+	if search.active && ctrl && key_code.just_pressed(KeyCode::KeyG) {
+		key_code.clear();
+
+		let original = search.original.clone();
+
+		*search = Search::default();
+
+		prompt_refresh.send(PromptRefresh::new(original.clone()));
+		character_update.send(Update(original));
+	}
+}
+
+pub fn cleanup(mut search: ResMut<Search>) {
+	*search = Search::default();
+}
@@ -0,0 +1,347 @@
+use bevy::{
+	prelude::{
+		Commands, DespawnRecursiveExt, Entity, EventReader, EventWriter, Query, Res, ResMut,
+		Resource, State, With,
+	},
+	time::Time,
+	ui::{Style, Val},
+	window::Window,
+};
+
+use super::{event::PanelRefresh, panel::Panel, state::Console};
+use crate::core::{event::CvarChanged, Actuator, Cvar, Value};
+
+
+
+pub const HEIGHT: &str = "console_height";
+
+pub const SPEED: &str = "console_speed";
+
+
+#[derive(Resource, Default, Debug)]
+pub struct Slide {
+	pub progress: f32,
+	pub half:     bool,
+}
+
+impl Slide {
+	pub fn height(&self, actuator: &Actuator) -> f32 {
+		let height = actuator
+			.cvar(HEIGHT)
+			.and_then(|v| v.get().as_float())
+			.unwrap_or(0.5) as f32;
+
+		if self.half {
+			height / 2.0
+		}
+		else {
+			height
+		}
+	}
+
+	pub fn top(&self, height: f32) -> Val {
+		Val::Vh((self.progress - 1.0) * height * 100.0)
+	}
+}
+
+
+
+pub fn height() -> Cvar {
+	Cvar::new(
+		HEIGHT,
+		Value::Float(0.5),
+	)
+	.about("console height as a fraction of the window height")
+	.range(
+		0.1, 1.0,
+	)
+}
+
+pub fn speed() -> Cvar {
+	Cvar::new(
+		SPEED,
+		Value::Float(0.15),
+	)
+	.about("seconds the console takes to slide open or closed, 0 is instant")
+	.range(
+		0.0, 2.0,
+	)
+}
+
+pub fn animate(
+	mut commands: Commands,
+	time: Res<Time>,
+	actuator: Res<Actuator>,
+	console: Res<State<Console>>,
+	mut slide: ResMut<Slide>,
+	mut panel: Query<
+		(
+			Entity,
+			&mut Style,
+		),
+		With<Panel>,
+	>,
+) {
+	let target = match console.get() {
+		| Console::Open => 1.0,
+		| Console::Close => 0.0,
+	};
+
+	let speed = actuator
+		.cvar(SPEED)
+		.and_then(|v| v.get().as_float())
+		.unwrap_or_default() as f32;
+
+	let step = if speed > 0.0 {
+		time.delta_seconds() / speed
+	}
+	else {
+		1.0
+	};
+
+	let progress = if slide.progress < target {
+		(slide.progress + step).min(target)
+	}
+	else {
+		(slide.progress - step).max(target)
+	};
+
+	slide.progress = progress;
+
+	let height = slide.height(&actuator);
+
+	for (id, mut style) in panel.iter_mut() {
+		if progress <= 0.0 && target <= 0.0 {
+			commands
+				.entity(id)
+				.despawn_recursive();
+
+			continue;
+		}
+
+		let top = slide.top(height);
+
+		if style.top != top {
+			style.top = top;
+		}
+
+		if style.height != Val::Vh(height * 100.0) {
+			style.height = Val::Vh(height * 100.0);
+		}
+	}
+}
+
+pub fn sync(
+	actuator: Res<Actuator>,
+	slide: Res<Slide>,
+	window: Query<&Window>,
+	mut panel: Query<&mut Panel>,
+	mut cvar_changed: EventReader<CvarChanged>,
+	mut panel_refresh: EventWriter<PanelRefresh>,
+) {
+	if !cvar_changed
+		.read()
+		.any(|v| v.is(HEIGHT))
+	{
+		return;
+	}
+
+	let height = window.single().height() * slide.height(&actuator);
+
+	for mut panel in panel.iter_mut() {
+		panel.limit_display(height);
+	}
+
+	panel_refresh.send(PanelRefresh);
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use bevy::{
+		app::{App, Update},
+		prelude::{AppExtStates, NextState},
+		state::app::StatesPlugin,
+		ui::{Style, Val},
+		MinimalPlugins,
+	};
+
+	use super::{animate, height, speed, Slide, HEIGHT, SPEED};
+	use crate::{
+		core::Actuator,
+		plugins::console::{panel::Panel, state::Console},
+	};
+
+
+	fn actuator() -> Actuator {
+		let mut actuator = Actuator::default();
+
+		actuator.register_cvar(height());
+		actuator.register_cvar(speed());
+
+		actuator
+	}
+
+
+	#[test]
+	fn height_and_half() {
+		let mut actuator = actuator();
+		let mut slide = Slide::default();
+
+		assert_eq!(
+			slide.height(&actuator),
+			0.5
+		);
+
+		assert!(
+			actuator
+				.set_cvar(HEIGHT, "0.8")
+				.is_ok()
+		);
+		assert_eq!(
+			slide.height(&actuator),
+			0.8
+		);
+
+		slide.half = true;
+
+		assert_eq!(
+			slide.height(&actuator),
+			0.4
+		);
+	}
+
+	#[test]
+	fn reject_out_of_range() {
+		let mut actuator = actuator();
+
+		for (name, input) in [
+			(
+				HEIGHT, "0.05",
+			),
+			(
+				HEIGHT, "1.5",
+			),
+			(
+				SPEED, "-1",
+			),
+			(
+				SPEED, "3",
+			),
+		] {
+			assert!(
+				actuator
+					.set_cvar(name, input)
+					.is_err()
+			);
+		}
+
+		assert!(
+			actuator
+				.set_cvar(SPEED, "0")
+				.is_ok()
+		);
+	}
+
+	#[test]
+	fn top_by_progress() {
+		let mut slide = Slide::default();
+
+		assert_eq!(
+			slide.top(0.5),
+			Val::Vh(-50.0)
+		);
+
+		slide.progress = 0.5;
+
+		assert_eq!(
+			slide.top(0.5),
+			Val::Vh(-25.0)
+		);
+
+		slide.progress = 1.0;
+
+		assert_eq!(
+			slide.top(0.5),
+			Val::Vh(0.0)
+		);
+	}
+
+	#[test]
+	fn instant_slide() {
+		let mut actuator = actuator();
+
+		assert!(
+			actuator
+				.set_cvar(SPEED, "0")
+				.is_ok()
+		);
+
+		let mut app = App::new();
+
+		app.add_plugins(
+			(
+				MinimalPlugins, StatesPlugin,
+			),
+		);
+		app.init_state::<Console>();
+		app.insert_resource(actuator);
+		app.init_resource::<Slide>();
+		app.add_systems(
+			Update, animate,
+		);
+
+		let panel = app
+			.world_mut()
+			.spawn(
+				(
+					Panel::default(),
+					Style::default(),
+				),
+			)
+			.id();
+
+		app.world_mut()
+			.resource_mut::<NextState<Console>>()
+			.set(Console::Open);
+		app.update();
+		app.update();
+
+		assert_eq!(
+			app.world()
+				.resource::<Slide>()
+				.progress,
+			1.0
+		);
+		assert_eq!(
+			app.world()
+				.get::<Style>(panel)
+				.map(|v| (v.top, v.height)),
+			Some(
+				(
+					Val::Vh(0.0),
+					Val::Vh(50.0),
+				),
+			)
+		);
+
+		app.world_mut()
+			.resource_mut::<NextState<Console>>()
+			.set(Console::Close);
+		app.update();
+		app.update();
+
+		assert_eq!(
+			app.world()
+				.resource::<Slide>()
+				.progress,
+			0.0
+		);
+		assert!(
+			app.world()
+				.get_entity(panel)
+				.is_none()
+		);
+	}
+}
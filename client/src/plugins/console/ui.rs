@@ -2,33 +2,56 @@ use bevy::{
 	asset::AssetServer,
 	color::Color,
 	input::ButtonInput,
+	math::Vec2,
 	prelude::{
 		BuildChildren, Commands, DespawnRecursiveExt, Entity, EventWriter, In, KeyCode, NextState,
 		NodeBundle, Query, Res, ResMut, TextBundle, With,
 	},
-	text::TextStyle,
+	text::{TextSection, TextStyle},
 	ui::{
-		AlignItems, BackgroundColor, Display, FlexDirection, JustifyContent, Overflow, Style,
-		UiRect, Val,
+		AlignItems, BackgroundColor, Display, FlexDirection, JustifyContent, Overflow,
+		PositionType, Style, UiRect, Val,
 	},
 	window::Window,
 };
 
 use super::{
 	event::{PanelRefresh, PromptRefresh},
-	panel::{Label, Panel},
-	prompt::Prompt,
+	panel::Panel,
+	prompt::{self, Prompt},
+	search::Search,
+	slide::Slide,
 	state::Console,
 };
-use crate::core::event::Execute;
+use crate::core::{
+	event::{Cancel, Execute},
+	Actuator,
+};
 
 pub fn setup(
 	mut commands: Commands,
 	asset_server: Res<AssetServer>,
+	actuator: Res<Actuator>,
+	slide: Res<Slide>,
 	mut window: Query<&mut Window>,
+	previous: Query<Entity, With<Panel>>,
 	mut panel_refresh: EventWriter<PanelRefresh>,
 ) {
-	let window = window.single_mut();
+	for id in previous.iter() {
+		commands
+			.entity(id)
+			.despawn_recursive();
+	}
+
+	let mut window = window.single_mut();
+
+	let height = slide.height(&actuator);
+
+	window.ime_enabled = true;
+	window.ime_position = Vec2::new(
+		20.0,
+		window.height() * height - 20.0,
+	);
 
 	let text_style = TextStyle {
 		font: asset_server.load("fonts/SourceHanSansCN-Regular.otf"),
@@ -39,30 +62,12 @@ pub fn setup(
 
 
 	let panel = Panel::new(
-		window.height(),
+		window.width(),
+		window.height() * height,
 		text_style.clone(),
 	);
 
-	let mut label: Vec<Entity> = Vec::new();
-
-	for _ in 0..panel.max_display {
-		let mut label_text = panel.from_section();
-
-		label_text
-			.style
-			.min_height = Val::Px(10.0);
-		label_text.style.margin = UiRect::left(Val::Px(10.0));
-
-		let id = commands
-			.spawn(
-				(
-					Label, label_text,
-				),
-			)
-			.id();
-
-		label.push(id);
-	}
+	let label = panel.labels(&mut commands);
 
 	let root = commands
 		.spawn(
@@ -73,8 +78,11 @@ pub fn setup(
 						display: Display::Flex,
 						flex_direction: FlexDirection::Column,
 						justify_content: JustifyContent::FlexEnd,
+						position_type: PositionType::Absolute,
+						top: slide.top(height),
+						left: Val::Px(0.0),
 						width: Val::Vw(100.0),
-						height: Val::Vh(100.0),
+						height: Val::Vh(height * 100.0),
 						padding: UiRect::all(Val::Px(10.0)),
 						overflow: Overflow::clip(),
 
@@ -112,10 +120,34 @@ pub fn setup(
 			|parent| {
 				parent.spawn(
 					(
-						Prompt,
-						TextBundle::from_section(
-							"$ _",
-							text_style.clone(),
+						Prompt::default(),
+						TextBundle::from_sections(
+							[
+								TextSection::new(
+									"$ ",
+									text_style.clone(),
+								),
+								TextSection::new(
+									"",
+									TextStyle {
+										color: prompt::PREEDIT,
+
+										..text_style.clone()
+									},
+								),
+								TextSection::new(
+									"_",
+									TextStyle {
+										color: prompt::CURSOR,
+
+										..text_style.clone()
+									},
+								),
+								TextSection::new(
+									"",
+									text_style.clone(),
+								),
+							],
 						),
 					),
 				);
@@ -133,16 +165,20 @@ pub fn setup(
 	panel_refresh.send(PanelRefresh);
 }
 
-pub fn cleanup(mut commands: Commands, panel: Query<Entity, With<Panel>>) {
-	for id in panel.iter() {
-		commands
-			.entity(id)
-			.despawn_recursive();
+pub fn cleanup(mut window: Query<&mut Window>) {
+	for mut window in window.iter_mut() {
+		window.ime_enabled = false;
 	}
 }
 
-pub fn open(mut key_code: ResMut<ButtonInput<KeyCode>>, mut console: ResMut<NextState<Console>>) {
+pub fn open(
+	mut key_code: ResMut<ButtonInput<KeyCode>>,
+	mut slide: ResMut<Slide>,
+	mut console: ResMut<NextState<Console>>,
+) {
 	if key_code.just_pressed(KeyCode::Backquote) {
+		slide.half = key_code.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
 		console.set(Console::Open);
 
 		key_code.clear();
@@ -158,28 +194,71 @@ pub fn close(
 		console.set(Console::Close);
 
 		key_code.clear();
-		prompt_refresh.send(PromptRefresh(String::from("")));
+		prompt_refresh.send(PromptRefresh::new(String::from("")));
+	}
+}
+
+pub fn interrupt(mut key_code: ResMut<ButtonInput<KeyCode>>, mut cancel: EventWriter<Cancel>) {
+	let ctrl = key_code.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+
+	if ctrl && key_code.just_pressed(KeyCode::KeyC) {
+		key_code.clear();
+
+		cancel.send(Cancel);
 	}
 }
 
 pub fn received_character(
-	In((character, enter)): In<(
+	In((character, cursor, enter)): In<(
 		String,
+		usize,
 		bool,
 	)>,
+	actuator: Res<Actuator>,
+	mut search: ResMut<Search>,
 	mut panel: Query<&mut Panel>,
 	mut execute: EventWriter<Execute>,
 	mut prompt_refresh: EventWriter<PromptRefresh>,
 ) {
 	let mut panel = panel.single_mut();
 
+	if search.active {
+		if enter {
+			panel.bubble();
+
+			execute.send(Execute::new(search.found.clone()));
+			prompt_refresh.send(PromptRefresh::new(String::from("")));
+
+			*search = Search::default();
+		}
+		else {
+			if character != search.query {
+				search.find(
+					&actuator, &character, None,
+				);
+			}
+
+			prompt_refresh.send(
+				PromptRefresh(
+					character, cursor,
+				),
+			);
+		}
+
+		return;
+	}
+
 	if enter {
 		panel.bubble();
 
 		execute.send(Execute::new(character));
-		prompt_refresh.send(PromptRefresh(String::from("")));
+		prompt_refresh.send(PromptRefresh::new(String::from("")));
 	}
 	else {
-		prompt_refresh.send(PromptRefresh(character));
+		prompt_refresh.send(
+			PromptRefresh(
+				character, cursor,
+			),
+		);
 	}
 }
@@ -0,0 +1,135 @@
+use ab_glyph::{Font as _, PxScale, ScaleFont};
+use bevy::text::Font;
+
+pub fn wrap(font: &Font, size: f32, width: f32, text: &str) -> Vec<String> {
+	let font = font
+		.font
+		.as_scaled(PxScale::from(size));
+
+	split(
+		width,
+		text,
+		|c| font.h_advance(font.glyph_id(c)),
+	)
+}
+
+fn split(width: f32, text: &str, measure: impl Fn(char) -> f32) -> Vec<String> {
+	let mut rows = vec![];
+	let mut row = String::new();
+	let mut row_width = 0.0;
+	let mut space: Option<(
+		usize,
+		f32,
+	)> = None;
+
+	for c in text.chars() {
+		let advance = measure(c);
+
+		if c.is_whitespace() && row.is_empty() && !rows.is_empty() {
+			continue;
+		}
+
+		if row_width + advance > width && !row.is_empty() {
+			if c.is_whitespace() {
+				rows.push(
+					row.trim_end()
+						.to_string(),
+				);
+
+				row.clear();
+				row_width = 0.0;
+				space = None;
+
+				continue;
+			}
+
+			if let Some((index, offset)) = space.take() {
+				let tail = row.split_off(index);
+
+				rows.push(
+					row.trim_end()
+						.to_string(),
+				);
+
+				row = tail;
+				row_width -= offset;
+			}
+			else {
+				rows.push(std::mem::take(&mut row));
+				row_width = 0.0;
+			}
+		}
+
+		row.push(c);
+		row_width += advance;
+
+		if c.is_whitespace() {
+			space = Some(
+				(
+					row.len(),
+					row_width,
+				),
+			);
+		}
+	}
+
+	rows.push(row);
+
+	rows
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use super::split;
+
+	fn mono(width: f32, text: &str) -> Vec<String> {
+		split(
+			width,
+			text,
+			|_| 1.0,
+		)
+	}
+
+
+	#[test]
+	fn fits() {
+		assert_eq!(
+			mono(10.0, "hello"),
+			vec!["hello"]
+		);
+		assert_eq!(
+			mono(10.0, ""),
+			vec![""]
+		);
+	}
+
+	#[test]
+	fn at_space() {
+		assert_eq!(
+			mono(8.0, "hello world"),
+			vec!["hello", "world"]
+		);
+		assert_eq!(
+			mono(5.0, "hello world"),
+			vec!["hello", "world"]
+		);
+		assert_eq!(
+			mono(11.0, "foo bar baz qux"),
+			vec!["foo bar baz", "qux"]
+		);
+	}
+
+	#[test]
+	fn long_word() {
+		assert_eq!(
+			mono(4.0, "abcdefghij"),
+			vec!["abcd", "efgh", "ij"]
+		);
+		assert_eq!(
+			mono(4.0, "ab abcdefgh"),
+			vec!["ab", "abcd", "efgh"]
+		);
+	}
+}
@@ -1,11 +0,0 @@
-use bevy::ecs::event::Event;
-
-
-#[derive(Event, Default)]
-pub enum Fps {
-	#[default]
-	Close,
-	Open,
-
-	State,
-}
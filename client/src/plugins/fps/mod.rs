@@ -1,4 +1,3 @@
-pub mod event;
 pub mod state;
 pub mod ui;
 
@@ -9,27 +8,26 @@ use bevy::{
 	state::{
 		app::AppExtStates,
 		condition,
-		state::{OnEnter, OnExit},
+		state::{NextState, OnEnter, OnExit, State},
 	},
 };
 
-use crate::core::AddCommandEvent;
+use crate::core::{event::CvarChanged, AddCvar};
 
 
 pub struct Plugin;
 
 impl bevy::app::Plugin for Plugin {
 	fn build(&self, app: &mut App) {
-		app.add_plugins(FrameTimeDiagnosticsPlugin::default());
+		app.add_plugins(FrameTimeDiagnosticsPlugin);
 
 
 		app.init_state::<state::Fps>();
+		app.register_type::<State<state::Fps>>();
+		app.register_type::<NextState<state::Fps>>();
 
 
-		app.add_command_event(
-			ui::new(),
-			ui::parse,
-		);
+		app.add_cvar(ui::cvar());
 
 		app.add_systems(
 			OnEnter(state::Fps::On),
@@ -44,7 +42,7 @@ impl bevy::app::Plugin for Plugin {
 		app.add_systems(
 			Update,
 			(
-				ui::display.run_if(common_conditions::on_event::<event::Fps>()),
+				ui::sync.run_if(common_conditions::on_event::<CvarChanged>()),
 				ui::update.run_if(condition::in_state(state::Fps::On)),
 			),
 		);
@@ -1,6 +1,6 @@
-use bevy::state::state::States;
+use bevy::{reflect::Reflect, state::state::States};
 
-#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States, Reflect)]
 pub enum Fps {
 	#[default]
 	Off,
@@ -4,40 +4,35 @@ use bevy::{
 	ecs::{
 		component::Component,
 		entity::Entity,
-		event::{EventReader, EventWriter},
+		event::EventReader,
 		query::With,
 		system::{Commands, Query, Res, ResMut},
 	},
 	hierarchy::DespawnRecursiveExt,
-	state::state::{NextState, State},
+	state::state::NextState,
 	text::{Text, TextStyle},
 	ui::{node_bundles::TextBundle, PositionType, Style, Val, ZIndex},
 };
-use clap;
 
-use super::{event, state};
-use crate::core::{event::Feedback, Level};
+use super::state;
+use crate::core::{event::CvarChanged, Cvar, Value};
 
 
 
-#[derive(Component)]
-pub struct FpsText;
+pub const CVAR: &str = "fps";
 
 
-pub fn new() -> clap::Command {
-	clap::Command::new("fps")
-		.about("to display on the screen top-right corner")
-		.arg(clap::arg!([value] "0 or 1").value_parser(clap::value_parser!(u8)))
-}
+#[derive(Component)]
+pub struct FpsText;
 
-pub fn parse(arg: clap::ArgMatches) -> Option<event::Fps> {
-	match arg.get_one::<u8>("value") {
-		| Some(0) => Some(event::Fps::Close),
-		| Some(1) => Some(event::Fps::Open),
 
-		| None => Some(event::Fps::State),
-		| _ => None,
-	}
+pub fn cvar() -> Cvar {
+	Cvar::new(
+		CVAR,
+		Value::Bool(false),
+	)
+	.about("to display on the screen top-right corner")
+	.command()
 }
 
 pub fn setup(mut commands: Commands) {
@@ -79,41 +74,22 @@ pub fn cleanup(mut commands: Commands, mark: Query<Entity, With<FpsText>>) {
 	}
 }
 
-
-pub fn display(
-	fps_state: Res<State<state::Fps>>,
+pub fn sync(
 	mut fps_next_state: ResMut<NextState<state::Fps>>,
-	mut fps_event: EventReader<event::Fps>,
-	mut feedback: EventWriter<Feedback>,
+	mut cvar_changed: EventReader<CvarChanged>,
 ) {
-	for v in fps_event.read() {
-		match v {
-			| event::Fps::Open => {
-				fps_next_state.set(state::Fps::On);
-			},
-			| event::Fps::Close => {
-				fps_next_state.set(state::Fps::Off);
-			},
-
-			| _ => {},
-		};
-
-		let message = match v {
-			| event::Fps::Open => state::Fps::On,
-			| event::Fps::Close => state::Fps::Off,
-
-			| event::Fps::State => fps_state.get().clone(),
+	for v in cvar_changed
+		.read()
+		.filter(|v| v.is(CVAR))
+	{
+		let state = if v.value.as_bool() == Some(true) {
+			state::Fps::On
+		}
+		else {
+			state::Fps::Off
 		};
 
-		feedback.send(
-			Feedback::Append(
-				format!(
-					"fps {}",
-					message as u8
-				),
-				Level::Success,
-			),
-		);
+		fps_next_state.set(state);
 	}
 }
 
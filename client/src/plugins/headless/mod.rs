@@ -0,0 +1,177 @@
+pub mod stdin;
+
+use std::{
+	env,
+	io::{self, IsTerminal, Write},
+};
+
+use bevy::{
+	app::{App, AppExit, Startup, Update},
+	ecs::{
+		event::{EventReader, EventWriter},
+		schedule::common_conditions,
+		system::{Commands, Res, ResMut},
+	},
+	prelude::{resource_exists, IntoSystemConfigs},
+};
+use stdin::Stdin;
+
+use crate::core::{
+	event::{Execute, Feedback, Recorded},
+	Actuator, Level, Tasks,
+};
+
+pub fn setup(mut commands: Commands) {
+	commands.insert_resource(Stdin::spawn());
+}
+
+pub fn receive(
+	actuator: Res<Actuator>,
+	tasks: Res<Tasks>,
+	mut stdin: ResMut<Stdin>,
+	mut feedback: EventReader<Feedback>,
+	mut recorded: EventReader<Recorded>,
+	mut execute: EventWriter<Execute>,
+	mut app_exit: EventWriter<AppExit>,
+) {
+	let closed = stdin.is_closed();
+
+	let lines = stdin.receive();
+
+	let busy = !lines.is_empty()
+		|| feedback.read().count() > 0
+		|| recorded.read().count() > 0
+		|| !actuator.is_idle()
+		|| !tasks.is_empty();
+
+	for v in lines {
+		execute.send(Execute::new(v));
+	}
+
+	if closed && stdin.settle(busy) {
+		app_exit.send(AppExit::Success);
+	}
+}
+
+pub fn print(mut recorded: EventReader<Recorded>) {
+	let color = io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none();
+
+	let mut stdout = io::stdout().lock();
+
+	for v in recorded.read() {
+		let code = match v.0.level {
+			| Level::Info => "0",
+			| Level::Success => "32",
+			| Level::Error => "31",
+			| Level::Warn => "33",
+			| Level::Display => "2",
+		};
+
+		let _ = if color {
+			writeln!(
+				stdout,
+				"\x1b[{code}m{}\x1b[0m",
+				v.0.message
+			)
+		}
+		else {
+			writeln!(
+				stdout,
+				"{}",
+				v.0.message
+			)
+		};
+	}
+
+	let _ = stdout.flush();
+}
+
+
+pub struct Plugin;
+
+impl bevy::app::Plugin for Plugin {
+	fn build(&self, app: &mut App) {
+		app.add_systems(
+			Startup, setup,
+		);
+
+		app.add_systems(
+			Update,
+			(
+				receive.run_if(resource_exists::<Stdin>),
+				print.run_if(common_conditions::on_event::<Recorded>()),
+			),
+		);
+	}
+}
+
+
+
+
+#[cfg(test)]
+mod test {
+	use std::sync::mpsc;
+
+	use bevy::{
+		app::{App, AppExit, Update},
+		ecs::event::Events,
+		state::app::StatesPlugin,
+		MinimalPlugins,
+	};
+
+	use super::{receive, stdin::Stdin};
+	use crate::core::{self, event::Execute, user};
+
+
+	fn exited(app: &mut App, frames: usize) -> bool {
+		for _ in 0..frames {
+			app.update();
+
+			if !app
+				.world()
+				.resource::<Events<AppExit>>()
+				.is_empty()
+			{
+				return true;
+			}
+		}
+
+		false
+	}
+
+
+	#[test]
+	fn wait_for_repeating_timer() {
+		user::scratch("headless");
+
+		let mut app = App::new();
+
+		app.add_plugins(
+			(
+				MinimalPlugins,
+				StatesPlugin,
+				core::Plugin,
+			),
+		);
+		app.add_systems(
+			Update, receive,
+		);
+
+		let (sender, receiver) = mpsc::channel();
+
+		sender
+			.send(String::from("every 10f clear"))
+			.unwrap();
+
+		drop(sender);
+
+		app.insert_resource(Stdin::new(receiver));
+
+		assert!(!exited(&mut app, 30));
+
+		app.world_mut()
+			.send_event(Execute::new(String::from("cancel 1")));
+
+		assert!(exited(&mut app, 30));
+	}
+}
@@ -0,0 +1,97 @@
+use std::{
+	io::{self, BufRead},
+	sync::{
+		mpsc::{self, Receiver},
+		Mutex,
+	},
+	thread,
+};
+
+use bevy::prelude::Resource;
+
+
+
+const QUIET_FRAMES: u32 = 3;
+
+
+#[derive(Resource)]
+pub struct Stdin {
+	receiver: Mutex<Receiver<String>>,
+	closed:   bool,
+	quiet:    u32,
+}
+
+impl Stdin {
+	pub fn spawn() -> Self {
+		let (sender, receiver) = mpsc::channel();
+
+		thread::spawn(
+			move || {
+				for line in io::stdin()
+					.lock()
+					.lines()
+				{
+					let Ok(line) = line
+					else {
+						break;
+					};
+
+					if sender
+						.send(line)
+						.is_err()
+					{
+						break;
+					}
+				}
+			},
+		);
+
+		Self::new(receiver)
+	}
+
+	pub fn new(receiver: Receiver<String>) -> Self {
+		Self {
+			receiver: Mutex::new(receiver),
+			closed:   false,
+			quiet:    0,
+		}
+	}
+
+	pub fn is_closed(&self) -> bool {
+		self.closed
+	}
+
+	pub fn settle(&mut self, busy: bool) -> bool {
+		if busy {
+			self.quiet = 0;
+		}
+		else {
+			self.quiet += 1;
+		}
+
+		self.quiet >= QUIET_FRAMES
+	}
+
+	pub fn receive(&mut self) -> Vec<String> {
+		let Ok(receiver) = self.receiver.lock()
+		else {
+			return vec![];
+		};
+
+		let mut lines = vec![];
+
+		loop {
+			match receiver.try_recv() {
+				| Ok(line) => lines.push(line),
+				| Err(mpsc::TryRecvError::Empty) => break,
+				| Err(mpsc::TryRecvError::Disconnected) => {
+					self.closed = true;
+
+					break;
+				},
+			}
+		}
+
+		lines
+	}
+}
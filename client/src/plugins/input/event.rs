@@ -1,12 +0,0 @@
-use bevy::ecs::event::Event;
-
-
-#[derive(Event, Default)]
-pub enum Input {
-	#[default]
-	None,
-	Gamepad,
-	Keyboard,
-
-	State,
-}
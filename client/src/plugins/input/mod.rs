@@ -1,78 +1,48 @@
-pub mod event;
 pub mod state;
 
 
 use bevy::{
 	app::{App, Update},
-	ecs::{
-		event::{EventReader, EventWriter},
-		schedule::common_conditions,
-		system::{Res, ResMut},
-	},
+	ecs::{event::EventReader, schedule::common_conditions, system::ResMut},
 	prelude::{AppExtStates, IntoSystemConfigs},
 	state::state::{NextState, State},
 };
 
-use crate::core::{event::Feedback, AddCommandEvent, Level};
+use crate::core::{event::CvarChanged, AddCvar, Cvar, Value};
 
-pub fn new() -> clap::Command {
-	clap::Command::new("input")
-		.about("input type")
-		.arg(
-			clap::arg!([value] "0 = none; 1 = gamepad; 2 = keyboard")
-				.value_parser(clap::value_parser!(u8)),
-		)
-}
 
-pub fn parse(arg: clap::ArgMatches) -> Option<event::Input> {
-	match arg.get_one::<u8>("value") {
-		| Some(0) => Some(event::Input::None),
-		| Some(1) => Some(event::Input::Gamepad),
-		| Some(2) => Some(event::Input::Keyboard),
 
-		| None => Some(event::Input::State),
-		| _ => None,
-	}
+pub const CVAR: &str = "input";
+
+
+pub fn cvar() -> Cvar {
+	Cvar::new(
+		CVAR,
+		Value::Integer(0),
+	)
+	.about("input type, 0 = none; 1 = gamepad; 2 = keyboard")
+	.range(
+		0.0, 2.0,
+	)
+	.command()
 }
 
-pub fn toggle(
-	input: Res<State<state::Input>>,
+pub fn sync(
 	mut input_next: ResMut<NextState<state::Input>>,
-	mut input_event: EventReader<event::Input>,
-	mut feedback: EventWriter<Feedback>,
+	mut cvar_changed: EventReader<CvarChanged>,
 ) {
-	for v in input_event.read() {
-		match v {
-			| event::Input::None => {
-				input_next.set(state::Input::None);
-			},
-			| event::Input::Gamepad => {
-				input_next.set(state::Input::Gamepad);
-			},
-			| event::Input::Keyboard => {
-				input_next.set(state::Input::Keyboard);
-			},
-
-			| _ => {},
+	for v in cvar_changed
+		.read()
+		.filter(|v| v.is(CVAR))
+	{
+		let state = match v.value.as_integer() {
+			| Some(1) => state::Input::Gamepad,
+			| Some(2) => state::Input::Keyboard,
+
+			| _ => state::Input::None,
 		};
 
-		let message = match v {
-			| event::Input::None => state::Input::None,
-			| event::Input::Gamepad => state::Input::Gamepad,
-			| event::Input::Keyboard => state::Input::Keyboard,
-
-			| event::Input::State => input.get().clone(),
-		};
-
-		feedback.send(
-			Feedback::Append(
-				format!(
-					"input {}",
-					message as u8
-				),
-				Level::Success,
-			),
-		);
+		input_next.set(state);
 	}
 }
 
@@ -82,16 +52,15 @@ pub struct Plugin;
 impl bevy::app::Plugin for Plugin {
 	fn build(&self, app: &mut App) {
 		app.init_state::<state::Input>();
+		app.register_type::<State<state::Input>>();
+		app.register_type::<NextState<state::Input>>();
 
-		app.add_command_event(
-			new(),
-			parse,
-		);
+		app.add_cvar(cvar());
 
 
 		app.add_systems(
 			Update,
-			(toggle.run_if(common_conditions::on_event::<event::Input>()),),
+			sync.run_if(common_conditions::on_event::<CvarChanged>()),
 		);
 	}
 }
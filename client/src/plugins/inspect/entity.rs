@@ -0,0 +1,397 @@
+use bevy::{
+	core::Name,
+	ecs::{
+		reflect::{AppTypeRegistry, ReflectComponent},
+		world::World,
+	},
+	hierarchy::DespawnRecursiveExt,
+	utils::get_short_name,
+};
+
+use super::reflect;
+use crate::core::locale::tr;
+
+pub fn list(world: &World, filter: &Option<String>) -> Result<String, String> {
+	let filter = filter
+		.as_ref()
+		.map(|v| v.to_lowercase());
+
+	let list = world
+		.iter_entities()
+		.filter_map(
+			|v| {
+				let components = world
+					.inspect_entity(v.id())
+					.iter()
+					.map(|v| get_short_name(v.name()))
+					.collect::<Vec<_>>();
+
+				let name = v
+					.get::<Name>()
+					.map(|v| v.as_str())
+					.unwrap_or_default();
+
+				let matched = filter
+					.as_ref()
+					.is_none_or(
+						|filter| {
+							name.to_lowercase()
+								.contains(filter) || components.iter().any(
+								|v| {
+									v.to_lowercase()
+										.contains(filter)
+								},
+							)
+						},
+					);
+
+				matched.then(
+					|| {
+						format!(
+							"{:>10}  {name}  [{}]",
+							v.id().to_string(),
+							components.join(", ")
+						)
+					},
+				)
+			},
+		)
+		.collect::<Vec<_>>();
+
+	if list.is_empty() {
+		return Err(tr!("no entity matches"));
+	}
+
+	Ok(list.join("\n"))
+}
+
+pub fn get(world: &World, entity: &str, component: &str) -> Result<String, String> {
+	let entity = reflect::entity(
+		world, entity,
+	)?;
+
+	let registry = world
+		.resource::<AppTypeRegistry>()
+		.read();
+
+	let registration = reflect::registration(
+		&registry, component,
+	)?;
+
+	let value = registration
+		.data::<ReflectComponent>()
+		.ok_or_else(
+			|| {
+				tr!(
+					"type `{component}` is not a component",
+					component = component
+				)
+			},
+		)?
+		.reflect(world.entity(entity))
+		.ok_or_else(
+			|| {
+				tr!(
+					"entity `{entity}` has no `{component}`",
+					entity = entity,
+					component = component
+				)
+			},
+		)?;
+
+	Ok(format!("{value:#?}"))
+}
+
+pub fn set(world: &mut World, entity: &str, path: &str, input: &str) -> Result<String, String> {
+	let entity = reflect::entity(
+		world, entity,
+	)?;
+
+	let registry = world
+		.resource::<AppTypeRegistry>()
+		.clone();
+	let registry = registry.read();
+
+	let (component, field) = reflect::split_path(path);
+
+	let registration = reflect::registration(
+		&registry, component,
+	)?;
+
+	let mut value = registration
+		.data::<ReflectComponent>()
+		.ok_or_else(
+			|| {
+				tr!(
+					"type `{component}` is not a component",
+					component = component
+				)
+			},
+		)?
+		.reflect_mut(world.entity_mut(entity))
+		.ok_or_else(
+			|| {
+				tr!(
+					"entity `{entity}` has no `{component}`",
+					entity = entity,
+					component = component
+				)
+			},
+		)?;
+
+	reflect::assign(
+		&registry,
+		value.as_reflect_mut(),
+		field,
+		input,
+	)?;
+
+	Ok(format!("{entity} {path} = {input}"))
+}
+
+pub fn despawn(world: &mut World, entity: &str) -> Result<String, String> {
+	let entity = reflect::entity(
+		world, entity,
+	)?;
+
+	world
+		.entity_mut(entity)
+		.despawn_recursive();
+
+	Ok(
+		tr!(
+			"{entity} despawned",
+			entity = entity
+		),
+	)
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use bevy::{
+		core::Name,
+		ecs::{entity::Entity, reflect::AppTypeRegistry, world::World},
+		math::Vec3,
+		transform::components::Transform,
+	};
+
+	use super::{despawn, get, list, set};
+
+
+	fn world() -> (
+		World,
+		Entity,
+	) {
+		let mut world = World::new();
+
+		world.init_resource::<AppTypeRegistry>();
+
+		{
+			let registry = world.resource::<AppTypeRegistry>();
+			let mut registry = registry.write();
+
+			registry.register::<Name>();
+			registry.register::<Transform>();
+		}
+
+		let player = world
+			.spawn(
+				(
+					Name::new("player"),
+					Transform::default(),
+				),
+			)
+			.id();
+
+		world.spawn(Name::new("tree"));
+
+		(
+			world, player,
+		)
+	}
+
+
+	#[test]
+	fn list_by_filter() {
+		let (world, player) = world();
+
+		let all = list(
+			&world, &None,
+		)
+		.unwrap();
+
+		assert_eq!(
+			all.lines().count(),
+			2
+		);
+
+		let found = list(
+			&world,
+			&Some(String::from("TRANSFORM")),
+		)
+		.unwrap();
+
+		assert!(found.contains(&player.to_string()));
+		assert!(found.contains("player"));
+		assert!(!found.contains("tree"));
+
+		assert!(
+			list(
+				&world,
+				&Some(String::from("rock"))
+			)
+			.is_err()
+		);
+	}
+
+	#[test]
+	fn get_and_set() {
+		let (mut world, player) = world();
+
+		let id = player.to_string();
+
+		assert!(
+			get(&world, &id, "Transform")
+				.unwrap()
+				.contains("translation")
+		);
+
+		assert_eq!(
+			set(
+				&mut world,
+				&player
+					.index()
+					.to_string(),
+				"Transform.translation.x",
+				"3.5"
+			),
+			Ok(format!("{player} Transform.translation.x = 3.5"))
+		);
+
+		set(
+			&mut world,
+			&id,
+			"Transform.scale",
+			"(x: 2.0, y: 2.0, z: 2.0)",
+		)
+		.unwrap();
+
+		set(
+			&mut world, &id, "Name", "hero",
+		)
+		.unwrap();
+
+		let entity = world.entity(player);
+
+		assert_eq!(
+			entity
+				.get::<Transform>()
+				.map(|v| (v.translation.x, v.scale)),
+			Some(
+				(
+					3.5,
+					Vec3::splat(2.0)
+				)
+			)
+		);
+
+		assert_eq!(
+			entity
+				.get::<Name>()
+				.map(|v| v.as_str()),
+			Some("hero")
+		);
+	}
+
+	#[test]
+	fn entity_error() {
+		let (mut world, player) = world();
+
+		let tree = list(
+			&world,
+			&Some(String::from("tree")),
+		)
+		.unwrap()
+		.split_whitespace()
+		.next()
+		.unwrap()
+		.to_string();
+
+		for (entity, component, error) in [
+			(
+				"x", "Transform", "invalid entity `x`",
+			),
+			(
+				"99",
+				"Transform",
+				"entity `99` does not exist",
+			),
+			(
+				&format!(
+					"{}v9",
+					player.index()
+				),
+				"Transform",
+				"does not exist",
+			),
+			(
+				&tree, "Transform", "has no `Transform`",
+			),
+			(
+				&tree,
+				"Vec3",
+				"type `Vec3` is not a component",
+			),
+		] {
+			let result = get(
+				&world, entity, component,
+			);
+
+			assert!(
+				result
+					.as_ref()
+					.is_err_and(|v| v.contains(error)),
+				"{entity} {component}: {result:?}"
+			);
+		}
+
+		assert!(
+			set(
+				&mut world,
+				&player.to_string(),
+				"Transform.translation.w",
+				"1"
+			)
+			.is_err()
+		);
+	}
+
+	#[test]
+	fn despawn_entity() {
+		let (mut world, player) = world();
+
+		assert_eq!(
+			despawn(
+				&mut world,
+				&player.to_string()
+			),
+			Ok(format!("{player} despawned"))
+		);
+
+		assert!(
+			world
+				.get_entity(player)
+				.is_none()
+		);
+
+		assert!(
+			despawn(
+				&mut world,
+				&player.to_string()
+			)
+			.is_err()
+		);
+	}
+}
@@ -0,0 +1,28 @@
+use bevy::ecs::event::Event;
+
+
+#[derive(Event)]
+pub enum Ent {
+	List(Option<String>),
+	Get(
+		String,
+		String,
+	),
+	Set(
+		String,
+		String,
+		String,
+	),
+	Despawn(String),
+}
+
+
+#[derive(Event)]
+pub enum Res {
+	List,
+	Get(String),
+	Set(
+		String,
+		String,
+	),
+}
@@ -0,0 +1,280 @@
+pub mod entity;
+pub mod event;
+pub mod reflect;
+pub mod resource;
+
+use bevy::{
+	app::{App, Update},
+	ecs::{event::Events, schedule::common_conditions, world::World},
+	prelude::IntoSystemConfigs,
+};
+
+use crate::core::{event::Feedback, AddCommandEvent, Level, Permission, SetPermission};
+
+pub fn new_ent() -> clap::Command {
+	clap::Command::new("ent")
+		.about("inspect and edit entities")
+		.subcommand_required(true)
+		.subcommand(
+			clap::Command::new("list")
+				.about("list the entities")
+				.arg(clap::arg!([filter] "name or component")),
+		)
+		.subcommand(
+			clap::Command::new("get")
+				.about("show a component of an entity")
+				.arg(clap::arg!(<entity> "entity, e.g. 12v1"))
+				.arg(clap::arg!(<component> "component type, e.g. Transform")),
+		)
+		.subcommand(
+			clap::Command::new("set")
+				.about("set a component field of an entity")
+				.arg(clap::arg!(<entity> "entity, e.g. 12v1"))
+				.arg(clap::arg!(<path> "component field, e.g. Transform.translation.x"))
+				.arg(clap::arg!(<value> "value in RON, e.g. 1.5 or (x:1,y:2,z:3)")),
+		)
+		.subcommand(
+			clap::Command::new("despawn")
+				.about("despawn an entity and its children")
+				.arg(clap::arg!(<entity> "entity, e.g. 12v1")),
+		)
+}
+
+pub fn new_res() -> clap::Command {
+	clap::Command::new("res")
+		.about("inspect and edit resources")
+		.subcommand_required(true)
+		.subcommand(clap::Command::new("list").about("list the reflected resources"))
+		.subcommand(
+			clap::Command::new("get")
+				.about("show a resource")
+				.arg(clap::arg!(<resource> "resource type, e.g. Time or State<Fps>")),
+		)
+		.subcommand(
+			clap::Command::new("set")
+				.about("set a resource field")
+				.arg(clap::arg!(<path> "resource field, e.g. Time<Virtual>.context.relative_speed"))
+				.arg(clap::arg!(<value> "value in RON, e.g. 0.5 or Pending(On)")),
+		)
+}
+
+pub fn parse_ent(arg: clap::ArgMatches) -> Option<event::Ent> {
+	let value = |arg: &clap::ArgMatches, id: &str| {
+		arg.get_one::<String>(id)
+			.cloned()
+	};
+
+	match arg.subcommand()? {
+		| ("list", arg) => {
+			Some(
+				event::Ent::List(
+					value(
+						arg, "filter",
+					),
+				),
+			)
+		},
+		| ("get", arg) => {
+			Some(
+				event::Ent::Get(
+					value(
+						arg, "entity",
+					)?,
+					value(
+						arg, "component",
+					)?,
+				),
+			)
+		},
+		| ("set", arg) => {
+			Some(
+				event::Ent::Set(
+					value(
+						arg, "entity",
+					)?,
+					value(
+						arg, "path",
+					)?,
+					value(
+						arg, "value",
+					)?,
+				),
+			)
+		},
+		| ("despawn", arg) => {
+			Some(
+				event::Ent::Despawn(
+					value(
+						arg, "entity",
+					)?,
+				),
+			)
+		},
+
+		| _ => None,
+	}
+}
+
+pub fn parse_res(arg: clap::ArgMatches) -> Option<event::Res> {
+	let value = |arg: &clap::ArgMatches, id: &str| {
+		arg.get_one::<String>(id)
+			.cloned()
+	};
+
+	match arg.subcommand()? {
+		| ("list", _) => Some(event::Res::List),
+		| ("get", arg) => {
+			Some(
+				event::Res::Get(
+					value(
+						arg, "resource",
+					)?,
+				),
+			)
+		},
+		| ("set", arg) => {
+			Some(
+				event::Res::Set(
+					value(
+						arg, "path",
+					)?,
+					value(
+						arg, "value",
+					)?,
+				),
+			)
+		},
+
+		| _ => None,
+	}
+}
+
+pub fn update_ent(world: &mut World) {
+	let ent_event = world
+		.resource_mut::<Events<event::Ent>>()
+		.drain()
+		.collect::<Vec<_>>();
+
+	for v in ent_event {
+		let result = match v {
+			| event::Ent::List(filter) => {
+				entity::list(
+					world, &filter,
+				)
+			},
+			| event::Ent::Get(entity, component) => {
+				entity::get(
+					world, &entity, &component,
+				)
+			},
+			| event::Ent::Set(entity, path, value) => {
+				entity::set(
+					world, &entity, &path, &value,
+				)
+			},
+			| event::Ent::Despawn(entity) => {
+				entity::despawn(
+					world, &entity,
+				)
+			},
+		};
+
+		let feedback = match result {
+			| Ok(message) => {
+				Feedback::Append(
+					String::from("ent"),
+					message,
+					Level::Success,
+				)
+			},
+			| Err(message) => {
+				Feedback::Append(
+					String::from("ent"),
+					message,
+					Level::Error,
+				)
+			},
+		};
+
+		world.send_event(feedback);
+		world.send_event(Feedback::Done(String::from("ent")));
+	}
+}
+
+pub fn update_res(world: &mut World) {
+	let res_event = world
+		.resource_mut::<Events<event::Res>>()
+		.drain()
+		.collect::<Vec<_>>();
+
+	for v in res_event {
+		let result = match v {
+			| event::Res::List => resource::list(world),
+			| event::Res::Get(resource) => {
+				resource::get(
+					world, &resource,
+				)
+			},
+			| event::Res::Set(path, value) => {
+				resource::set(
+					world, &path, &value,
+				)
+			},
+		};
+
+		let feedback = match result {
+			| Ok(message) => {
+				Feedback::Append(
+					String::from("res"),
+					message,
+					Level::Display,
+				)
+			},
+			| Err(message) => {
+				Feedback::Append(
+					String::from("res"),
+					message,
+					Level::Error,
+				)
+			},
+		};
+
+		world.send_event(feedback);
+		world.send_event(Feedback::Done(String::from("res")));
+	}
+}
+
+
+pub struct Plugin;
+
+impl bevy::app::Plugin for Plugin {
+	fn build(&self, app: &mut App) {
+		app.add_command_event(
+			new_ent(),
+			parse_ent,
+		);
+
+		app.add_command_event(
+			new_res(),
+			parse_res,
+		);
+
+		app.set_permission(
+			"ent",
+			Permission::Cheat,
+		);
+		app.set_permission(
+			"res",
+			Permission::Developer,
+		);
+
+
+		app.add_systems(
+			Update,
+			(
+				update_ent.run_if(common_conditions::on_event::<event::Ent>()),
+				update_res.run_if(common_conditions::on_event::<event::Res>()),
+			),
+		);
+	}
+}
@@ -0,0 +1,169 @@
+use bevy::{
+	core::Name,
+	ecs::{entity::Entity, world::World},
+	reflect::{serde::TypedReflectDeserializer, GetPath, Reflect, TypeRegistration, TypeRegistry},
+};
+
+use crate::core::locale::tr;
+
+pub fn entity(world: &World, input: &str) -> Result<Entity, String> {
+	let (index, generation) = match input.split_once('v') {
+		| Some((index, generation)) => {
+			(
+				index,
+				Some(generation),
+			)
+		},
+		| None => {
+			(
+				input, None,
+			)
+		},
+	};
+
+	let index = index
+		.parse::<u32>()
+		.map_err(
+			|_| {
+				tr!(
+					"invalid entity `{input}`",
+					input = input
+				)
+			},
+		)?;
+
+	let generation = generation
+		.map(|v| v.parse::<u32>())
+		.transpose()
+		.map_err(
+			|_| {
+				tr!(
+					"invalid entity `{input}`",
+					input = input
+				)
+			},
+		)?;
+
+	world
+		.iter_entities()
+		.map(|v| v.id())
+		.find(
+			|v| {
+				v.index() == index
+					&& generation.is_none_or(|generation| v.generation() == generation)
+			},
+		)
+		.ok_or_else(
+			|| {
+				tr!(
+					"entity `{input}` does not exist",
+					input = input
+				)
+			},
+		)
+}
+
+pub fn registration<'a>(
+	registry: &'a TypeRegistry,
+	name: &str,
+) -> Result<&'a TypeRegistration, String> {
+	registry
+		.get_with_short_type_path(name)
+		.or_else(|| registry.get_with_type_path(name))
+		.or_else(|| registry.get_with_short_type_path(&format!("{name}<()>")))
+		.ok_or_else(
+			|| {
+				tr!(
+					"type `{name}` is not registered",
+					name = name
+				)
+			},
+		)
+}
+
+pub fn split_path(
+	input: &str,
+) -> (
+	&str,
+	&str,
+) {
+	input
+		.split_once('.')
+		.unwrap_or(
+			(
+				input, "",
+			),
+		)
+}
+
+pub fn assign(
+	registry: &TypeRegistry,
+	target: &mut dyn Reflect,
+	path: &str,
+	input: &str,
+) -> Result<(), String> {
+	let field = if path.is_empty() {
+		target
+	}
+	else {
+		target
+			.reflect_path_mut(path)
+			.map_err(
+				|v| {
+					tr!(
+						"invalid path `{path}`: {reason}",
+						path = path,
+						reason = v
+					)
+				},
+			)?
+	};
+
+	if let Some(value) = field.downcast_mut::<String>() {
+		*value = input.to_string();
+
+		return Ok(());
+	}
+
+	if let Some(value) = field.downcast_mut::<Name>() {
+		*value = Name::new(input.to_string());
+
+		return Ok(());
+	}
+
+	let info = field
+		.get_represented_type_info()
+		.ok_or_else(|| tr!("field type is unknown"))?;
+
+	let registration = registry
+		.get(info.type_id())
+		.ok_or_else(
+			|| {
+				tr!(
+					"type `{name}` is not registered",
+					name = info.type_path()
+				)
+			},
+		)?;
+
+	let value = ron::Options::default()
+		.from_str_seed(
+			input,
+			TypedReflectDeserializer::new(
+				registration, registry,
+			),
+		)
+		.map_err(
+			|v| {
+				tr!(
+					"cannot parse `{input}`: {reason}",
+					input = input,
+					reason = v
+				)
+			},
+		)?;
+
+	field
+		.try_apply(&*value)
+		.map_err(|v| v.to_string())
+}
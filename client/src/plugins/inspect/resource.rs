@@ -0,0 +1,300 @@
+use bevy::{
+	ecs::{
+		reflect::{AppTypeRegistry, ReflectResource},
+		world::World,
+	},
+	reflect::TypeRegistry,
+};
+
+use super::reflect;
+use crate::core::locale::tr;
+
+pub fn list(world: &World) -> Result<String, String> {
+	let registry = world
+		.resource::<AppTypeRegistry>()
+		.read();
+
+	let mut list = registry
+		.iter()
+		.filter(
+			|v| {
+				v.data::<ReflectResource>()
+					.and_then(|v| v.reflect(world))
+					.is_some()
+			},
+		)
+		.map(
+			|v| {
+				v.type_info()
+					.type_path_table()
+					.short_path()
+					.to_string()
+			},
+		)
+		.collect::<Vec<_>>();
+
+	if list.is_empty() {
+		return Err(tr!("no reflected resource exists"));
+	}
+
+	list.sort();
+
+	Ok(list.join("\n"))
+}
+
+pub fn get(world: &World, resource: &str) -> Result<String, String> {
+	let registry = world
+		.resource::<AppTypeRegistry>()
+		.read();
+
+	let value = reflect_resource(
+		&registry, resource,
+	)?
+	.reflect(world)
+	.ok_or_else(
+		|| {
+			tr!(
+				"resource `{resource}` does not exist",
+				resource = resource
+			)
+		},
+	)?;
+
+	Ok(format!("{value:#?}"))
+}
+
+pub fn set(world: &mut World, path: &str, input: &str) -> Result<String, String> {
+	let registry = world
+		.resource::<AppTypeRegistry>()
+		.clone();
+	let registry = registry.read();
+
+	let (resource, field) = reflect::split_path(path);
+
+	let mut value = reflect_resource(
+		&registry, resource,
+	)?
+	.reflect_mut(world)
+	.ok_or_else(
+		|| {
+			tr!(
+				"resource `{resource}` does not exist",
+				resource = resource
+			)
+		},
+	)?;
+
+	reflect::assign(
+		&registry,
+		value.as_reflect_mut(),
+		field,
+		input,
+	)?;
+
+	Ok(format!("{path} = {input}"))
+}
+
+fn reflect_resource<'a>(
+	registry: &'a TypeRegistry,
+	resource: &str,
+) -> Result<&'a ReflectResource, String> {
+	reflect::registration(
+		registry, resource,
+	)?
+	.data::<ReflectResource>()
+	.ok_or_else(
+		|| {
+			tr!(
+				"type `{resource}` is not a resource",
+				resource = resource
+			)
+		},
+	)
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use bevy::{
+		ecs::{
+			reflect::{AppTypeRegistry, ReflectResource},
+			system::Resource,
+			world::World,
+		},
+		reflect::Reflect,
+	};
+
+	use super::{get, list, set};
+
+
+	#[derive(Reflect, Clone, Copy, PartialEq, Debug, Default)]
+	enum Mode {
+		#[default]
+		Walk,
+		Run,
+	}
+
+	#[derive(Resource, Reflect, Clone, PartialEq, Debug, Default)]
+	#[reflect(Resource)]
+	struct Speed {
+		value: f32,
+		label: String,
+		mode:  Mode,
+	}
+
+	#[derive(Reflect, Default)]
+	struct Loose;
+
+
+	fn world() -> World {
+		let mut world = World::new();
+
+		world.init_resource::<AppTypeRegistry>();
+		world.init_resource::<Speed>();
+
+		{
+			let registry = world.resource::<AppTypeRegistry>();
+			let mut registry = registry.write();
+
+			registry.register::<Speed>();
+			registry.register::<Loose>();
+		}
+
+		world
+	}
+
+
+	#[test]
+	fn list_and_get() {
+		let mut world = world();
+
+		assert_eq!(
+			list(&world),
+			Ok(String::from("Speed"))
+		);
+
+		assert!(
+			get(&world, "Speed")
+				.unwrap()
+				.contains("label: \"\"")
+		);
+
+		world.remove_resource::<Speed>();
+
+		assert!(list(&world).is_err());
+
+		assert_eq!(
+			get(&world, "Speed"),
+			Err(String::from("resource `Speed` does not exist"))
+		);
+	}
+
+	#[test]
+	fn set_field() {
+		let mut world = world();
+
+		assert_eq!(
+			set(&mut world, "Speed.value", "2.5"),
+			Ok(String::from("Speed.value = 2.5"))
+		);
+
+		set(
+			&mut world, "Speed.label", "fast lane",
+		)
+		.unwrap();
+
+		set(
+			&mut world, "Speed.mode", "Run",
+		)
+		.unwrap();
+
+		assert_eq!(
+			world.resource::<Speed>(),
+			&Speed {
+				value: 2.5,
+				label: String::from("fast lane"),
+				mode:  Mode::Run,
+			}
+		);
+	}
+
+	#[test]
+	fn set_ron() {
+		let mut world = world();
+
+		let input = r#"(value: 4.0, label: "slow", mode: Walk)"#;
+
+		set(
+			&mut world, "Speed", input,
+		)
+		.unwrap();
+
+		let expected = Speed {
+			value: 4.0,
+			label: String::from("slow"),
+			mode:  Mode::Walk,
+		};
+
+		assert_eq!(
+			world.resource::<Speed>(),
+			&expected
+		);
+
+		let output = get(
+			&world, "Speed",
+		)
+		.unwrap();
+
+		assert_eq!(
+			output,
+			format!(
+				"{:#?}",
+				&expected as &dyn Reflect
+			)
+		);
+	}
+
+	#[test]
+	fn set_error() {
+		let mut world = world();
+
+		for (path, input, error) in [
+			(
+				"Nope.value",
+				"1",
+				"type `Nope` is not registered",
+			),
+			(
+				"Loose",
+				"Loose",
+				"type `Loose` is not a resource",
+			),
+			(
+				"Speed.missing", "1", "invalid path `missing`",
+			),
+			(
+				"Speed.value", "fast", "cannot parse `fast`",
+			),
+			(
+				"Speed.mode", "Fly", "cannot parse `Fly`",
+			),
+		] {
+			let result = set(
+				&mut world, path, input,
+			);
+
+			assert!(
+				result
+					.as_ref()
+					.is_err_and(|v| v.starts_with(error)),
+				"{path}: {result:?}"
+			);
+		}
+
+		assert_eq!(
+			world.resource::<Speed>(),
+			&Speed::default()
+		);
+	}
+}
@@ -0,0 +1,9 @@
+use bevy::ecs::event::Event;
+
+
+#[derive(Event)]
+pub enum Log {
+	Set(String),
+
+	State,
+}
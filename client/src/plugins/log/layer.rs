@@ -0,0 +1,301 @@
+use std::{
+	fmt,
+	sync::{
+		atomic::{AtomicU8, Ordering},
+		mpsc::{self, Receiver, Sender},
+		Arc, Mutex,
+	},
+};
+
+use bevy::{
+	log::{
+		tracing_subscriber::{filter::LevelFilter, Layer},
+		BoxedLayer,
+	},
+	prelude::Resource,
+	utils::tracing::{
+		field::{Field, Visit},
+		Event, Subscriber,
+	},
+};
+
+use crate::core::Level;
+
+
+
+pub const LEVELS: [&str; 6] = ["off", "error", "warn", "info", "debug", "trace"];
+
+const DEFAULT_LEVEL: u8 = 2;
+
+
+
+pub struct Message {
+	pub message: String,
+	pub level:   Level,
+	pub target:  String,
+}
+
+
+#[derive(Resource)]
+pub struct Log {
+	receiver: Mutex<Receiver<Message>>,
+	minimum:  Arc<AtomicU8>,
+}
+
+impl Log {
+	pub fn layer() -> (
+		Self,
+		BoxedLayer,
+	) {
+		let (sender, receiver) = mpsc::channel();
+		let minimum = Arc::new(AtomicU8::new(DEFAULT_LEVEL));
+
+		(
+			Self {
+				receiver: Mutex::new(receiver),
+				minimum:  minimum.clone(),
+			},
+			Box::new(Forward { sender, minimum }),
+		)
+	}
+
+	pub fn level(&self) -> &'static str {
+		LEVELS[self
+			.minimum
+			.load(Ordering::Relaxed) as usize]
+	}
+
+	pub fn set_level(&self, name: &str) -> bool {
+		let Some(index) = LEVELS
+			.iter()
+			.position(|v| v.eq(&name))
+		else {
+			return false;
+		};
+
+		self.minimum.store(
+			index as u8,
+			Ordering::Relaxed,
+		);
+
+		true
+	}
+
+	pub fn is_filtered(&self) -> bool {
+		let limit = LevelFilter::current()
+			.into_level()
+			.map_or(
+				0, rank,
+			);
+
+		self.minimum
+			.load(Ordering::Relaxed)
+			> limit
+	}
+
+	pub fn receive(&self) -> Vec<Message> {
+		self.receiver
+			.lock()
+			.map(|v| v.try_iter().collect())
+			.unwrap_or_default()
+	}
+}
+
+
+
+fn rank(level: bevy::log::Level) -> u8 {
+	match level {
+		| bevy::log::Level::ERROR => 1,
+		| bevy::log::Level::WARN => 2,
+		| bevy::log::Level::INFO => 3,
+		| bevy::log::Level::DEBUG => 4,
+		| bevy::log::Level::TRACE => 5,
+	}
+}
+
+
+struct Forward {
+	sender:  Sender<Message>,
+	minimum: Arc<AtomicU8>,
+}
+
+impl<S> Layer<S> for Forward
+where S: Subscriber
+{
+	fn on_event(&self, event: &Event<'_>, _: bevy::log::tracing_subscriber::layer::Context<'_, S>) {
+		let metadata = event.metadata();
+
+		let rank = rank(*metadata.level());
+
+		if rank
+			> self
+				.minimum
+				.load(Ordering::Relaxed)
+		{
+			return;
+		}
+
+		let level = match rank {
+			| 1 => Level::Error,
+			| 2 => Level::Warn,
+			| 3 => Level::Info,
+			| _ => Level::Display,
+		};
+
+		let mut visitor = Visitor::default();
+
+		event.record(&mut visitor);
+
+		let _ = self.sender.send(
+			Message {
+				message: visitor.message,
+				level,
+				target: metadata
+					.target()
+					.to_string(),
+			},
+		);
+	}
+}
+
+
+
+#[derive(Default)]
+struct Visitor {
+	message: String,
+}
+
+impl Visit for Visitor {
+	fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+		let value = if field.name() == "message" {
+			format!("{value:?}")
+		}
+		else {
+			format!(
+				"{}={value:?}",
+				field.name()
+			)
+		};
+
+		if !self.message.is_empty() {
+			self.message.push(' ');
+		}
+
+		self.message
+			.push_str(&value);
+	}
+}
+
+
+
+
+#[cfg(test)]
+mod test {
+	use bevy::{
+		log::{debug, error, info, trace, tracing_subscriber::prelude::*, warn},
+		utils::tracing::subscriber,
+	};
+
+	use super::Log;
+	use crate::core::Level;
+
+
+	fn emit(layer: bevy::log::BoxedLayer) {
+		subscriber::with_default(
+			bevy::log::tracing_subscriber::registry().with(layer),
+			|| {
+				error!("e");
+				warn!(
+					count = 2,
+					"w"
+				);
+				info!("i");
+				debug!("d");
+				trace!("t");
+			},
+		);
+	}
+
+	fn received(
+		log: &Log,
+	) -> Vec<(
+		String,
+		Level,
+	)> {
+		log.receive()
+			.into_iter()
+			.map(
+				|v| {
+					(
+						v.message, v.level,
+					)
+				},
+			)
+			.collect()
+	}
+
+
+	#[test]
+	fn forward_by_level() {
+		let (log, layer) = Log::layer();
+
+		assert_eq!(
+			log.level(),
+			"warn"
+		);
+
+		assert!(!log.set_level("loud"));
+		assert!(log.set_level("trace"));
+
+		emit(layer);
+
+		assert_eq!(
+			received(&log),
+			vec![
+				(
+					String::from("e"),
+					Level::Error
+				),
+				(
+					String::from("w count=2"),
+					Level::Warn
+				),
+				(
+					String::from("i"),
+					Level::Info
+				),
+				(
+					String::from("d"),
+					Level::Display
+				),
+				(
+					String::from("t"),
+					Level::Display
+				),
+			]
+		);
+	}
+
+	#[test]
+	fn filter_by_level() {
+		let (log, layer) = Log::layer();
+
+		emit(layer);
+
+		assert_eq!(
+			received(&log)
+				.into_iter()
+				.map(|(v, _)| v)
+				.collect::<Vec<_>>(),
+			vec!["e", "w count=2"]
+		);
+
+		let (log, layer) = Log::layer();
+
+		log.set_level("off");
+
+		emit(layer);
+
+		assert!(received(&log).is_empty());
+	}
+}
@@ -0,0 +1,134 @@
+pub mod event;
+pub mod layer;
+
+use bevy::{
+	app::{App, Update},
+	ecs::{
+		event::{EventReader, EventWriter},
+		schedule::common_conditions,
+		system::{Res, ResMut},
+	},
+	log::BoxedLayer,
+	prelude::{resource_exists, IntoSystemConfigs},
+};
+use layer::Log;
+
+use crate::core::{
+	event::{Feedback, Refresh},
+	locale::tr,
+	Actuator, AddCommandEvent, Level, SOURCE,
+};
+
+pub fn new() -> clap::Command {
+	clap::Command::new("loglevel")
+		.about("minimum level of log messages shown in the console")
+		.arg(clap::arg!([level] "log level").value_parser(layer::LEVELS))
+}
+
+pub fn parse(arg: clap::ArgMatches) -> Option<event::Log> {
+	match arg.get_one::<String>("level") {
+		| Some(level) => Some(event::Log::Set(level.clone())),
+
+		| None => Some(event::Log::State),
+	}
+}
+
+pub fn log_layer(app: &mut App) -> Option<BoxedLayer> {
+	let (log, layer) = Log::layer();
+
+	app.insert_resource(log);
+
+	Some(layer)
+}
+
+pub fn update(
+	log: Option<Res<Log>>,
+	mut log_event: EventReader<event::Log>,
+	mut feedback: EventWriter<Feedback>,
+) {
+	for v in log_event.read() {
+		let Some(log) = &log
+		else {
+			feedback.send(
+				Feedback::Append(
+					String::from("loglevel"),
+					tr!("log forwarding is not enabled"),
+					Level::Warn,
+				),
+			);
+			feedback.send(Feedback::Done(String::from("loglevel")));
+
+			continue;
+		};
+
+		if let event::Log::Set(level) = v {
+			log.set_level(level);
+		}
+
+		feedback.send(
+			Feedback::Append(
+				String::from("loglevel"),
+				tr!(
+					"loglevel {level}",
+					level = log.level()
+				),
+				Level::Success,
+			),
+		);
+
+		if log.is_filtered() {
+			feedback.send(
+				Feedback::Append(
+					String::from("loglevel"),
+					tr!(
+						"{level} messages are dropped by the log filter, start with RUST_LOG={level} to see them",
+						level = log.level()
+					),
+					Level::Warn,
+				),
+			);
+		}
+
+		feedback.send(Feedback::Done(String::from("loglevel")));
+	}
+}
+
+pub fn receive(log: Res<Log>, mut actuator: ResMut<Actuator>, mut refresh: EventWriter<Refresh>) {
+	let messages = log.receive();
+
+	if messages.is_empty() {
+		return;
+	}
+
+	for v in messages {
+		actuator.set_record_source(&v.target);
+		actuator.push_record(
+			&v.message, v.level,
+		);
+	}
+
+	actuator.set_record_source(SOURCE);
+
+	refresh.send(Refresh);
+}
+
+
+pub struct Plugin;
+
+impl bevy::app::Plugin for Plugin {
+	fn build(&self, app: &mut App) {
+		app.add_command_event(
+			new(),
+			parse,
+		);
+
+
+		app.add_systems(
+			Update,
+			(
+				update.run_if(common_conditions::on_event::<event::Log>()),
+				receive.run_if(resource_exists::<Log>),
+			),
+		);
+	}
+}
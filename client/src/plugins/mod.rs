@@ -1,7 +1,13 @@
 pub mod event;
 
+pub mod bind;
 pub mod character;
 pub mod console;
 pub mod fps;
+pub mod headless;
 pub mod input;
+pub mod inspect;
+pub mod log;
+#[cfg(feature = "remote")]
+pub mod remote;
 pub mod window;
@@ -0,0 +1,99 @@
+pub mod server;
+
+use std::env;
+
+use bevy::{
+	app::{App, Startup, Update},
+	ecs::{
+		event::{EventReader, EventWriter},
+		schedule::common_conditions,
+		system::{Commands, ResMut},
+	},
+	prelude::IntoSystemConfigs,
+};
+use server::Server;
+
+use crate::core::{
+	event::{Execute, Feedback, Recorded},
+	locale::tr,
+	Level,
+};
+
+
+
+const ADDRESS: &str = "SLUGMA_REMOTE";
+
+const DEFAULT_ADDRESS: &str = "127.0.0.1:27015";
+
+
+
+pub fn setup(mut commands: Commands, mut feedback: EventWriter<Feedback>) {
+	let address = env::var(ADDRESS).unwrap_or_else(|_| String::from(DEFAULT_ADDRESS));
+
+	match Server::bind(&address) {
+		| Ok(server) => {
+			commands.insert_resource(server);
+
+			feedback.send(
+				Feedback::Append(
+					String::from("remote"),
+					tr!(
+						"remote console listening on {address}",
+						address = address
+					),
+					Level::Display,
+				),
+			);
+		},
+		| Err(error) => {
+			feedback.send(
+				Feedback::Append(
+					String::from("remote"),
+					tr!(
+						"cannot listen on {address}: {error}",
+						address = address,
+						error = error
+					),
+					Level::Error,
+				),
+			);
+		},
+	}
+}
+
+pub fn receive(mut server: ResMut<Server>, mut execute: EventWriter<Execute>) {
+	server.accept();
+	server.flush();
+
+	for v in server.receive() {
+		execute.send(Execute::new(v));
+	}
+}
+
+pub fn send(mut server: ResMut<Server>, mut recorded: EventReader<Recorded>) {
+	for Recorded(entry) in recorded.read() {
+		server.send(
+			&entry.message, &entry.level,
+		);
+	}
+}
+
+
+pub struct Plugin;
+
+impl bevy::app::Plugin for Plugin {
+	fn build(&self, app: &mut App) {
+		app.add_systems(
+			Startup, setup,
+		);
+
+		app.add_systems(
+			Update,
+			(
+				receive,
+				send.run_if(common_conditions::on_event::<Recorded>()),
+			)
+				.run_if(common_conditions::resource_exists::<Server>),
+		);
+	}
+}
@@ -0,0 +1,375 @@
+#[cfg(unix)]
+use std::os::unix::{
+	fs::FileTypeExt,
+	net::{UnixListener, UnixStream},
+};
+use std::{
+	fs,
+	io::{self, ErrorKind, Read, Write},
+	net::{TcpListener, TcpStream},
+};
+
+use bevy::prelude::Resource;
+
+use crate::core::Level;
+
+
+
+const MAX_PENDING: usize = 16 * 1024 * 1024;
+
+
+trait Stream: Read + Write + Send + Sync {}
+
+impl<T> Stream for T where T: Read + Write + Send + Sync
+{
+}
+
+
+
+enum Listener {
+	Tcp(TcpListener),
+	#[cfg(unix)]
+	Unix(UnixListener),
+}
+
+struct Client {
+	stream:  Box<dyn Stream>,
+	buffer:  Vec<u8>,
+	pending: Vec<u8>,
+}
+
+
+
+#[derive(Resource)]
+pub struct Server {
+	listener: Listener,
+	clients:  Vec<Client>,
+}
+
+impl Server {
+	pub fn bind(address: &str) -> io::Result<Self> {
+		let listener = if let Some(path) = address.strip_prefix("unix:") {
+			Self::bind_unix(path)?
+		}
+		else {
+			let listener = TcpListener::bind(address)?;
+
+			listener.set_nonblocking(true)?;
+
+			Listener::Tcp(listener)
+		};
+
+		Ok(
+			Self {
+				listener,
+				clients: Default::default(),
+			},
+		)
+	}
+
+	#[cfg(unix)]
+	fn bind_unix(path: &str) -> io::Result<Listener> {
+		if fs::metadata(path).is_ok_and(
+			|v| {
+				v.file_type()
+					.is_socket()
+			},
+		) {
+			fs::remove_file(path)?;
+		}
+
+		let listener = UnixListener::bind(path)?;
+
+		listener.set_nonblocking(true)?;
+
+		Ok(Listener::Unix(listener))
+	}
+
+	#[cfg(not(unix))]
+	fn bind_unix(_: &str) -> io::Result<Listener> {
+		Err(
+			io::Error::new(
+				ErrorKind::Unsupported,
+				"unix sockets are not supported",
+			),
+		)
+	}
+
+	pub fn accept(&mut self) {
+		loop {
+			let stream: io::Result<Box<dyn Stream>> = match &self.listener {
+				| Listener::Tcp(v) => {
+					v.accept()
+						.and_then(|(v, _)| Self::prepare_tcp(v))
+				},
+				#[cfg(unix)]
+				| Listener::Unix(v) => {
+					v.accept()
+						.and_then(|(v, _)| Self::prepare_unix(v))
+				},
+			};
+
+			let Ok(stream) = stream
+			else {
+				break;
+			};
+
+			self.clients.push(
+				Client {
+					stream,
+					buffer: Default::default(),
+					pending: Default::default(),
+				},
+			);
+		}
+	}
+
+	fn prepare_tcp(stream: TcpStream) -> io::Result<Box<dyn Stream>> {
+		stream.set_nonblocking(true)?;
+
+		Ok(Box::new(stream))
+	}
+
+	#[cfg(unix)]
+	fn prepare_unix(stream: UnixStream) -> io::Result<Box<dyn Stream>> {
+		stream.set_nonblocking(true)?;
+
+		Ok(Box::new(stream))
+	}
+
+	pub fn receive(&mut self) -> Vec<String> {
+		let mut lines = vec![];
+
+		self.clients.retain_mut(
+			|client| {
+				let mut buffer = [0; 1024];
+				let mut connected = true;
+
+				loop {
+					match client
+						.stream
+						.read(&mut buffer)
+					{
+						| Ok(0) => {
+							connected = false;
+
+							break;
+						},
+						| Ok(length) => {
+							client
+								.buffer
+								.extend_from_slice(&buffer[..length]);
+						},
+						| Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+						| Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+						| Err(_) => {
+							connected = false;
+
+							break;
+						},
+					}
+				}
+
+				while let Some(index) = client
+					.buffer
+					.iter()
+					.position(|v| *v == b'\n')
+				{
+					let line = client
+						.buffer
+						.drain(..=index)
+						.collect::<Vec<_>>();
+
+					lines.push(
+						String::from_utf8_lossy(&line)
+							.trim()
+							.to_string(),
+					);
+				}
+
+				connected
+			},
+		);
+
+		lines
+	}
+
+	pub fn send(&mut self, message: &str, level: &Level) {
+		let content = message
+			.split('\n')
+			.map(|v| format!("{level:?}\t{v}\n"))
+			.collect::<String>();
+
+		for client in self.clients.iter_mut() {
+			client
+				.pending
+				.extend_from_slice(content.as_bytes());
+		}
+
+		self.flush();
+	}
+
+	pub fn flush(&mut self) {
+		self.clients.retain_mut(
+			|client| {
+				while !client
+					.pending
+					.is_empty()
+				{
+					match client
+						.stream
+						.write(&client.pending)
+					{
+						| Ok(0) => return false,
+						| Ok(length) => {
+							client
+								.pending
+								.drain(..length);
+						},
+						| Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+						| Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+						| Err(_) => return false,
+					}
+				}
+
+				client.pending.len() <= MAX_PENDING
+			},
+		);
+	}
+}
+
+
+
+#[cfg(test)]
+mod test {
+	use std::{
+		io::{BufRead, BufReader, Write},
+		net::TcpStream,
+		thread,
+		time::Duration,
+	};
+
+	use super::{Listener, Server};
+	use crate::core::Level;
+
+
+	fn connect() -> (
+		Server,
+		TcpStream,
+	) {
+		let mut server = Server::bind("127.0.0.1:0").unwrap();
+
+		let Listener::Tcp(listener) = &server.listener
+		else {
+			unreachable!();
+		};
+
+		let stream = TcpStream::connect(
+			listener
+				.local_addr()
+				.unwrap(),
+		)
+		.unwrap();
+
+		while server
+			.clients
+			.is_empty()
+		{
+			server.accept();
+
+			thread::sleep(Duration::from_millis(1));
+		}
+
+		(
+			server, stream,
+		)
+	}
+
+
+	#[test]
+	fn reply() {
+		let (mut server, mut stream) = connect();
+
+		stream
+			.write_all(b"help\n")
+			.unwrap();
+
+		let mut lines = vec![];
+
+		while lines.is_empty() {
+			lines = server.receive();
+
+			thread::sleep(Duration::from_millis(1));
+		}
+
+		assert_eq!(
+			lines,
+			vec!["help"]
+		);
+
+		server.send(
+			"a\nb",
+			&Level::Success,
+		);
+
+		let mut reader = BufReader::new(stream);
+		let mut line = String::new();
+
+		reader
+			.read_line(&mut line)
+			.unwrap();
+		reader
+			.read_line(&mut line)
+			.unwrap();
+
+		assert_eq!(
+			line,
+			"Success\ta\nSuccess\tb\n"
+		);
+	}
+
+	#[test]
+	fn partial_write() {
+		let (mut server, stream) = connect();
+
+		let message = "x".repeat(1024);
+
+		for _ in 0..8192 {
+			server.send(
+				&message,
+				&Level::Info,
+			);
+		}
+
+		assert!(
+			!server.clients[0]
+				.pending
+				.is_empty()
+		);
+
+		let reader = thread::spawn(
+			move || {
+				BufReader::new(stream)
+					.lines()
+					.take(8192)
+					.map(|v| v.unwrap())
+					.filter(|v| v.len() == message.len() + 5)
+					.count()
+			},
+		);
+
+		while !server.clients[0]
+			.pending
+			.is_empty()
+		{
+			server.flush();
+
+			thread::sleep(Duration::from_millis(1));
+		}
+
+		assert_eq!(
+			reader.join().unwrap(),
+			8192
+		);
+	}
+}
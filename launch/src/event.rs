@@ -1 +1,2 @@
+#[allow(unused_imports)]
 use bevy::ecs::event::Event;
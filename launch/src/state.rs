@@ -1 +1,2 @@
+#[allow(unused_imports)]
 use bevy::state::state::States;
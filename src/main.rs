@@ -1,11 +1,38 @@
-use bevy::{app::App, DefaultPlugins};
-use client;
-use launch;
+use std::{env, time::Duration};
+
+use bevy::{
+	app::{App, ScheduleRunnerPlugin},
+	log::LogPlugin,
+	prelude::PluginGroup,
+	DefaultPlugins, MinimalPlugins,
+};
+
+const HEADLESS: &str = "--headless";
 
 fn main() {
 	let mut app = App::new();
 
-	app.add_plugins(DefaultPlugins);
+	if env::args().any(|v| v.eq(HEADLESS)) {
+		app.add_plugins(
+			MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(Duration::from_secs_f64(1.0 / 60.0))),
+		);
+		app.add_plugins(LogPlugin::default());
+
+		app.add_plugins(client::Headless);
+
+		app.run();
+
+		return;
+	}
+
+	app.add_plugins(
+		DefaultPlugins.set(
+			LogPlugin {
+				custom_layer: client::log_layer,
+				..Default::default()
+			},
+		),
+	);
 
 	app.add_plugins(client::Plugins);
 	app.add_plugins(launch::Plugins);